@@ -0,0 +1,318 @@
+//! Prints how many allocations, and how many bytes, each format asks the
+//! allocator for when it encodes and decodes a header and a block.
+
+#[cfg(feature = "quick-protobuf")]
+use quick_protobuf::{BytesReader, MessageRead};
+use serde_bench::alloc_counter::{measure, Allocations, CountingAllocator};
+#[cfg(feature = "quick-protobuf")]
+use serde_bench::bench_quick_protobuf::{Block as QuickBlock, Header as QuickHeader};
+use serde_bench::{Block, Header};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const TRANSACTIONS: usize = 100;
+const IO_SIZE: usize = 3;
+
+struct Row {
+    name: &'static str,
+    header: Option<(Allocations, Allocations)>,
+    block: Option<(Allocations, Allocations)>,
+}
+
+impl Row {
+    fn new(name: &'static str) -> Self {
+        Row {
+            name,
+            header: None,
+            block: None,
+        }
+    }
+
+    fn header<O: AsRef<[u8]>, R>(
+        mut self,
+        header: &Header,
+        to: fn(&Header) -> O,
+        from: fn(&[u8]) -> R,
+    ) -> Self {
+        self.header = Some(round_trip(header, to, from));
+        self
+    }
+
+    fn block<O: AsRef<[u8]>, R>(
+        mut self,
+        block: &Block,
+        to: fn(&Block) -> O,
+        from: fn(&[u8]) -> R,
+    ) -> Self {
+        self.block = Some(round_trip(block, to, from));
+        self
+    }
+}
+
+fn round_trip<T, O: AsRef<[u8]>, R>(
+    value: &T,
+    to: fn(&T) -> O,
+    from: fn(&[u8]) -> R,
+) -> (Allocations, Allocations) {
+    let (data, encode) = measure(|| to(value));
+    let (_, decode) = measure(|| from(data.as_ref()));
+    (encode, decode)
+}
+
+fn cells(allocations: Option<(Allocations, Allocations)>) -> [String; 4] {
+    match allocations {
+        Some((encode, decode)) => [
+            encode.count.to_string(),
+            encode.bytes.to_string(),
+            decode.count.to_string(),
+            decode.bytes.to_string(),
+        ],
+        None => Default::default(),
+    }
+}
+
+fn main() {
+    let header = Header::random();
+    let block = Block::random(TRANSACTIONS, IO_SIZE);
+    // The Avro schemas are parsed on first use, keep that out of the counts.
+    #[cfg(feature = "avro")]
+    serde_bench::bench_avro::schemas();
+
+    let rows: Vec<Row> = vec![
+        #[cfg(feature = "flatbuffers")]
+        Row::new("flatbuffers")
+            .header(&header, Header::to_flatbuffers, Header::from_flatbuffers)
+            .block(&block, Block::to_flatbuffers, Block::from_flatbuffers),
+        #[cfg(feature = "protobuf")]
+        Row::new("protobuf")
+            .header(&header, Header::to_protobuf, Header::from_protobuf)
+            .block(&block, Block::to_protobuf, Block::from_protobuf),
+        #[cfg(feature = "molecule")]
+        Row::new("molecule")
+            .header(&header, Header::to_molecule, Header::from_molecule)
+            .block(&block, Block::to_molecule, Block::from_molecule),
+        #[cfg(feature = "scale")]
+        Row::new("scale")
+            .header(&header, Header::to_scale, Header::from_scale)
+            .block(&block, Block::to_scale, Block::from_scale),
+        #[cfg(feature = "borsh")]
+        Row::new("borsh")
+            .header(&header, Header::to_borsh, Header::from_borsh)
+            .block(&block, Block::to_borsh, Block::from_borsh),
+        #[cfg(feature = "ssz")]
+        Row::new("ssz")
+            .header(&header, Header::to_ssz, Header::from_ssz)
+            .block(&block, Block::to_ssz, Block::from_ssz),
+        #[cfg(feature = "rkyv")]
+        Row::new("rkyv")
+            .header(&header, Header::to_rkyv, Header::from_rkyv)
+            .block(&block, Block::to_rkyv, Block::from_rkyv),
+        #[cfg(feature = "rkyv")]
+        Row::new("rkyv_unchecked")
+            .header(&header, Header::to_rkyv, |data| unsafe {
+                Header::from_rkyv_unchecked(data)
+            })
+            .block(&block, Block::to_rkyv, |data| unsafe {
+                Block::from_rkyv_unchecked(data)
+            }),
+        #[cfg(feature = "prost")]
+        Row::new("prost")
+            .header(&header, Header::to_prost, Header::from_prost)
+            .block(&block, Block::to_prost, Block::from_prost),
+        #[cfg(feature = "quick-protobuf")]
+        Row::new("quick_protobuf")
+            .header(
+                &header,
+                Header::to_quick_protobuf,
+                Header::from_quick_protobuf,
+            )
+            .block(&block, Block::to_quick_protobuf, Block::from_quick_protobuf),
+        #[cfg(feature = "quick-protobuf")]
+        Row::new("quick_protobuf_borrowed")
+            .header(&header, Header::to_quick_protobuf, |data| {
+                QuickHeader::from_reader(&mut BytesReader::from_bytes(data), data).unwrap();
+            })
+            .block(&block, Block::to_quick_protobuf, |data| {
+                QuickBlock::from_reader(&mut BytesReader::from_bytes(data), data).unwrap();
+            }),
+        #[cfg(feature = "avro")]
+        Row::new("avro")
+            .header(&header, Header::to_avro, Header::from_avro)
+            .block(&block, Block::to_avro, Block::from_avro),
+        #[cfg(feature = "avro")]
+        Row::new("avro_resolved").block(&block, Block::to_avro, Block::from_avro_resolved),
+        #[cfg(feature = "avro")]
+        Row::new("avro_container").block(
+            &block,
+            Block::to_avro_container,
+            Block::from_avro_container,
+        ),
+        #[cfg(feature = "thrift")]
+        Row::new("thrift_binary")
+            .header(
+                &header,
+                Header::to_thrift_binary,
+                Header::from_thrift_binary,
+            )
+            .block(&block, Block::to_thrift_binary, Block::from_thrift_binary),
+        #[cfg(feature = "thrift")]
+        Row::new("thrift_compact")
+            .header(
+                &header,
+                Header::to_thrift_compact,
+                Header::from_thrift_compact,
+            )
+            .block(&block, Block::to_thrift_compact, Block::from_thrift_compact),
+        #[cfg(feature = "json")]
+        Row::new("json")
+            .header(&header, Header::to_json, Header::from_json)
+            .block(&block, Block::to_json, Block::from_json),
+        #[cfg(feature = "json")]
+        Row::new("json_simd")
+            .header(&header, Header::to_json, Header::from_json_simd)
+            .block(&block, Block::to_json, Block::from_json_simd),
+        #[cfg(feature = "bcs")]
+        Row::new("bcs")
+            .header(&header, Header::to_bcs, Header::from_bcs)
+            .block(&block, Block::to_bcs, Block::from_bcs),
+        #[cfg(feature = "postcard")]
+        Row::new("postcard")
+            .header(&header, Header::to_postcard, Header::from_postcard)
+            .block(&block, Block::to_postcard, Block::from_postcard),
+        #[cfg(feature = "postcard")]
+        Row::new("postcard_heapless").header(
+            &header,
+            Header::to_postcard,
+            Header::from_postcard_heapless,
+        ),
+        #[cfg(feature = "der")]
+        Row::new("der")
+            .header(&header, Header::to_der, Header::from_der)
+            .block(&block, Block::to_der, Block::from_der),
+        #[cfg(feature = "xdr")]
+        Row::new("xdr")
+            .header(&header, Header::to_xdr, Header::from_xdr)
+            .block(&block, Block::to_xdr, Block::from_xdr),
+        #[cfg(feature = "raw")]
+        Row::new("raw")
+            .header(&header, Header::to_raw, Header::from_raw)
+            .block(&block, Block::to_raw, Block::from_raw),
+        #[cfg(feature = "flatbuffers")]
+        Row::new("flatbuffers_struct")
+            .header(
+                &header,
+                Header::to_flatbuffers_struct,
+                Header::from_flatbuffers_struct,
+            )
+            .block(
+                &block,
+                Block::to_flatbuffers_struct,
+                Block::from_flatbuffers_struct,
+            ),
+        #[cfg(feature = "molecule")]
+        Row::new("molecule_struct")
+            .header(
+                &header,
+                Header::to_molecule_struct,
+                Header::from_molecule_struct,
+            )
+            .block(
+                &block,
+                Block::to_molecule_struct,
+                Block::from_molecule_struct,
+            ),
+        #[cfg(feature = "prost")]
+        Row::new("prost_fixed")
+            .header(&header, Header::to_prost_fixed, Header::from_prost_fixed)
+            .block(&block, Block::to_prost_fixed, Block::from_prost_fixed),
+        #[cfg(feature = "flexbuffers")]
+        Row::new("flexbuffers")
+            .header(&header, Header::to_flexbuffers, Header::from_flexbuffers)
+            .block(&block, Block::to_flexbuffers, Block::from_flexbuffers),
+        #[cfg(feature = "bitcoin")]
+        Row::new("bitcoin")
+            .header(&header, Header::to_bitcoin, Header::from_bitcoin)
+            .block(&block, Block::to_bitcoin, Block::from_bitcoin),
+        #[cfg(feature = "bitcode")]
+        Row::new("bitcode")
+            .header(&header, Header::to_bitcode, Header::from_bitcode)
+            .block(&block, Block::to_bitcode, Block::from_bitcode),
+        #[cfg(feature = "bson")]
+        Row::new("bson")
+            .header(&header, Header::to_bson, Header::from_bson)
+            .block(&block, Block::to_bson, Block::from_bson),
+        #[cfg(feature = "sbe")]
+        Row::new("sbe")
+            .header(&header, Header::to_sbe, Header::from_sbe)
+            .block(&block, Block::to_sbe, Block::from_sbe),
+    ];
+
+    println!(
+        "Allocations per call and the bytes they request, block of {} transactions with io size {}\n",
+        TRANSACTIONS, IO_SIZE
+    );
+    let titles = [
+        "Format",
+        "Header to",
+        "bytes",
+        "Header from",
+        "bytes",
+        "Block to",
+        "bytes",
+        "Block from",
+        "bytes",
+    ];
+    let table: Vec<Vec<String>> = rows
+        .into_iter()
+        .map(|row| {
+            let mut cols = vec![row.name.to_string()];
+            cols.extend(cells(row.header).iter().cloned());
+            cols.extend(cells(row.block).iter().cloned());
+            cols
+        })
+        .collect();
+    let widths: Vec<usize> = titles
+        .iter()
+        .enumerate()
+        .map(|(i, title)| {
+            table
+                .iter()
+                .map(|cols| cols[i].len())
+                .chain(Some(title.len()))
+                .max()
+                .unwrap()
+        })
+        .collect();
+
+    let print_row = |cols: &[String]| {
+        let line: Vec<String> = cols
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (col, &width))| {
+                if i == 0 {
+                    format!("{:<width$}", col, width = width)
+                } else {
+                    format!("{:>width$}", col, width = width)
+                }
+            })
+            .collect();
+        println!("| {} |", line.join(" | "));
+    };
+    print_row(
+        &titles
+            .iter()
+            .map(|title| title.to_string())
+            .collect::<Vec<_>>(),
+    );
+    print_row(
+        &widths
+            .iter()
+            .map(|&width| "-".repeat(width))
+            .collect::<Vec<_>>(),
+    );
+    for cols in &table {
+        print_row(cols);
+    }
+}
@@ -0,0 +1,139 @@
+mod common;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    common::block(c, "commit_block", |s, block| {
+        #[cfg(feature = "flatbuffers")]
+        s.bench(
+            "flatbuffers",
+            block,
+            block.to_flatbuffers().len(),
+            |b, block| b.iter(|| common::sha256(block.to_flatbuffers())),
+        );
+        #[cfg(feature = "protobuf")]
+        s.bench("protobuf", block, block.to_protobuf().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_protobuf()))
+        });
+        #[cfg(feature = "molecule")]
+        s.bench("molecule", block, block.to_molecule().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_molecule()))
+        });
+        #[cfg(feature = "scale")]
+        s.bench("scale", block, block.to_scale().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_scale()))
+        });
+        #[cfg(feature = "borsh")]
+        s.bench("borsh", block, block.to_borsh().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_borsh()))
+        });
+        #[cfg(feature = "ssz")]
+        s.bench("ssz", block, block.to_ssz().len(), |b, block| {
+            b.iter(|| block.to_ssz_with_root())
+        });
+        #[cfg(feature = "rkyv")]
+        s.bench("rkyv", block, block.to_rkyv().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_rkyv()))
+        });
+        #[cfg(feature = "prost")]
+        s.bench("prost", block, block.to_prost().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_prost()))
+        });
+        #[cfg(feature = "quick-protobuf")]
+        s.bench(
+            "quick_protobuf",
+            block,
+            block.to_quick_protobuf().len(),
+            |b, block| b.iter(|| common::sha256(block.to_quick_protobuf())),
+        );
+        #[cfg(feature = "avro")]
+        s.bench("avro", block, block.to_avro().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_avro()))
+        });
+        #[cfg(feature = "thrift")]
+        s.bench(
+            "thrift_binary",
+            block,
+            block.to_thrift_binary().len(),
+            |b, block| b.iter(|| common::sha256(block.to_thrift_binary())),
+        );
+        #[cfg(feature = "thrift")]
+        s.bench(
+            "thrift_compact",
+            block,
+            block.to_thrift_compact().len(),
+            |b, block| b.iter(|| common::sha256(block.to_thrift_compact())),
+        );
+        #[cfg(feature = "json")]
+        s.bench("json", block, block.to_json().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_json()))
+        });
+        #[cfg(feature = "bcs")]
+        s.bench("bcs", block, block.to_bcs().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_bcs()))
+        });
+        #[cfg(feature = "postcard")]
+        s.bench("postcard", block, block.to_postcard().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_postcard()))
+        });
+        #[cfg(feature = "der")]
+        s.bench("der", block, block.to_der().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_der()))
+        });
+        #[cfg(feature = "xdr")]
+        s.bench("xdr", block, block.to_xdr().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_xdr()))
+        });
+        #[cfg(feature = "raw")]
+        s.bench("raw", block, block.to_raw().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_raw()))
+        });
+        #[cfg(feature = "flatbuffers")]
+        s.bench(
+            "flatbuffers_struct",
+            block,
+            block.to_flatbuffers_struct().len(),
+            |b, block| b.iter(|| common::sha256(block.to_flatbuffers_struct())),
+        );
+        #[cfg(feature = "molecule")]
+        s.bench(
+            "molecule_struct",
+            block,
+            block.to_molecule_struct().len(),
+            |b, block| b.iter(|| common::sha256(block.to_molecule_struct())),
+        );
+        #[cfg(feature = "prost")]
+        s.bench(
+            "prost_fixed",
+            block,
+            block.to_prost_fixed().len(),
+            |b, block| b.iter(|| common::sha256(block.to_prost_fixed())),
+        );
+        #[cfg(feature = "flexbuffers")]
+        s.bench(
+            "flexbuffers",
+            block,
+            block.to_flexbuffers().len(),
+            |b, block| b.iter(|| common::sha256(block.to_flexbuffers())),
+        );
+        #[cfg(feature = "bitcoin")]
+        s.bench("bitcoin", block, block.to_bitcoin().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_bitcoin()))
+        });
+        #[cfg(feature = "bitcode")]
+        s.bench("bitcode", block, block.to_bitcode().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_bitcode()))
+        });
+        #[cfg(feature = "bson")]
+        s.bench("bson", block, block.to_bson().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_bson()))
+        });
+        #[cfg(feature = "sbe")]
+        s.bench("sbe", block, block.to_sbe().len(), |b, block| {
+            b.iter(|| common::sha256(block.to_sbe()))
+        });
+    });
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
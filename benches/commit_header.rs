@@ -0,0 +1,148 @@
+mod common;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    common::header(c, "commit_header", |s, header| {
+        #[cfg(feature = "flatbuffers")]
+        s.bench(
+            "flatbuffers",
+            header,
+            header.to_flatbuffers().len(),
+            |b, header| b.iter(|| common::sha256(header.to_flatbuffers())),
+        );
+        #[cfg(feature = "protobuf")]
+        s.bench(
+            "protobuf",
+            header,
+            header.to_protobuf().len(),
+            |b, header| b.iter(|| common::sha256(header.to_protobuf())),
+        );
+        #[cfg(feature = "molecule")]
+        s.bench(
+            "molecule",
+            header,
+            header.to_molecule().len(),
+            |b, header| b.iter(|| common::sha256(header.to_molecule())),
+        );
+        #[cfg(feature = "scale")]
+        s.bench("scale", header, header.to_scale().len(), |b, header| {
+            b.iter(|| common::sha256(header.to_scale()))
+        });
+        #[cfg(feature = "borsh")]
+        s.bench("borsh", header, header.to_borsh().len(), |b, header| {
+            b.iter(|| common::sha256(header.to_borsh()))
+        });
+        #[cfg(feature = "ssz")]
+        s.bench("ssz", header, header.to_ssz().len(), |b, header| {
+            b.iter(|| header.to_ssz_with_root())
+        });
+        #[cfg(feature = "rkyv")]
+        s.bench("rkyv", header, header.to_rkyv().len(), |b, header| {
+            b.iter(|| common::sha256(header.to_rkyv()))
+        });
+        #[cfg(feature = "prost")]
+        s.bench("prost", header, header.to_prost().len(), |b, header| {
+            b.iter(|| common::sha256(header.to_prost()))
+        });
+        #[cfg(feature = "quick-protobuf")]
+        s.bench(
+            "quick_protobuf",
+            header,
+            header.to_quick_protobuf().len(),
+            |b, header| b.iter(|| common::sha256(header.to_quick_protobuf())),
+        );
+        #[cfg(feature = "avro")]
+        s.bench("avro", header, header.to_avro().len(), |b, header| {
+            b.iter(|| common::sha256(header.to_avro()))
+        });
+        #[cfg(feature = "thrift")]
+        s.bench(
+            "thrift_binary",
+            header,
+            header.to_thrift_binary().len(),
+            |b, header| b.iter(|| common::sha256(header.to_thrift_binary())),
+        );
+        #[cfg(feature = "thrift")]
+        s.bench(
+            "thrift_compact",
+            header,
+            header.to_thrift_compact().len(),
+            |b, header| b.iter(|| common::sha256(header.to_thrift_compact())),
+        );
+        #[cfg(feature = "json")]
+        s.bench("json", header, header.to_json().len(), |b, header| {
+            b.iter(|| common::sha256(header.to_json()))
+        });
+        #[cfg(feature = "bcs")]
+        s.bench("bcs", header, header.to_bcs().len(), |b, header| {
+            b.iter(|| common::sha256(header.to_bcs()))
+        });
+        #[cfg(feature = "postcard")]
+        s.bench(
+            "postcard",
+            header,
+            header.to_postcard().len(),
+            |b, header| b.iter(|| common::sha256(header.to_postcard())),
+        );
+        #[cfg(feature = "der")]
+        s.bench("der", header, header.to_der().len(), |b, header| {
+            b.iter(|| common::sha256(header.to_der()))
+        });
+        #[cfg(feature = "xdr")]
+        s.bench("xdr", header, header.to_xdr().len(), |b, header| {
+            b.iter(|| common::sha256(header.to_xdr()))
+        });
+        #[cfg(feature = "raw")]
+        s.bench("raw", header, header.to_raw().len(), |b, header| {
+            b.iter(|| common::sha256(header.to_raw()))
+        });
+        #[cfg(feature = "flatbuffers")]
+        s.bench(
+            "flatbuffers_struct",
+            header,
+            header.to_flatbuffers_struct().len(),
+            |b, header| b.iter(|| common::sha256(header.to_flatbuffers_struct())),
+        );
+        #[cfg(feature = "molecule")]
+        s.bench(
+            "molecule_struct",
+            header,
+            header.to_molecule_struct().len(),
+            |b, header| b.iter(|| common::sha256(header.to_molecule_struct())),
+        );
+        #[cfg(feature = "prost")]
+        s.bench(
+            "prost_fixed",
+            header,
+            header.to_prost_fixed().len(),
+            |b, header| b.iter(|| common::sha256(header.to_prost_fixed())),
+        );
+        #[cfg(feature = "flexbuffers")]
+        s.bench(
+            "flexbuffers",
+            header,
+            header.to_flexbuffers().len(),
+            |b, header| b.iter(|| common::sha256(header.to_flexbuffers())),
+        );
+        #[cfg(feature = "bitcoin")]
+        s.bench("bitcoin", header, header.to_bitcoin().len(), |b, header| {
+            b.iter(|| common::sha256(header.to_bitcoin()))
+        });
+        #[cfg(feature = "bitcode")]
+        s.bench("bitcode", header, header.to_bitcode().len(), |b, header| {
+            b.iter(|| common::sha256(header.to_bitcode()))
+        });
+        #[cfg(feature = "bson")]
+        s.bench("bson", header, header.to_bson().len(), |b, header| {
+            b.iter(|| common::sha256(header.to_bson()))
+        });
+        #[cfg(feature = "sbe")]
+        s.bench("sbe", header, header.to_sbe().len(), |b, header| {
+            b.iter(|| common::sha256(header.to_sbe()))
+        });
+    });
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -0,0 +1,135 @@
+//! Benchmark groups shared by the bench targets.
+//!
+//! Header benches run once per format and report the encoded size as
+//! `Throughput::Bytes`. Block benches are swept twice: over the number of
+//! transactions with a fixed io size, reported as `Throughput::Elements`
+//! (transactions per second), and over the io size with a fixed number of
+//! transactions, reported as `Throughput::Bytes` of each format's encoding.
+
+#![allow(dead_code)]
+
+use criterion::measurement::WallTime;
+use criterion::{Bencher, BenchmarkGroup, BenchmarkId, Criterion, Throughput};
+use serde_bench::{Block, Header};
+use sha2::{Digest, Sha256};
+
+pub const TRANSACTIONS: [usize; 5] = [1, 10, 100, 1_000, 10_000];
+pub const IO_SIZES: [usize; 3] = [1, 3, 16];
+
+/// The io size used while sweeping the number of transactions.
+pub const DEFAULT_IO_SIZE: usize = 3;
+/// The number of transactions used while sweeping the io size.
+pub const DEFAULT_TRANSACTIONS: usize = 100;
+
+/// Blocks from this many transactions on take long enough per iteration that
+/// the default 100 samples would stretch a single sweep to minutes.
+const LARGE_TRANSACTIONS: usize = 1_000;
+const LARGE_SAMPLE_SIZE: usize = 10;
+const SAMPLE_SIZE: usize = 100;
+
+/// Registers the benchmarks of every format for a single input.
+pub struct Sweep<'a, 'c> {
+    group: &'a mut BenchmarkGroup<'c, WallTime>,
+    parameter: Option<String>,
+    bytes: bool,
+}
+
+impl<'a, 'c> Sweep<'a, 'c> {
+    /// Benchmarks `f` on `input`, where `size` is the length of the message
+    /// in the benchmarked format.
+    pub fn bench<I: ?Sized>(
+        &mut self,
+        name: &str,
+        input: &I,
+        size: usize,
+        f: impl FnMut(&mut Bencher<WallTime>, &I),
+    ) {
+        if self.bytes {
+            self.group.throughput(Throughput::Bytes(size as u64));
+        }
+        match &self.parameter {
+            Some(parameter) => {
+                let id = BenchmarkId::new(name, parameter);
+                self.group.bench_with_input(id, input, f)
+            }
+            None => self.group.bench_with_input(name, input, f),
+        };
+    }
+
+    /// Benchmarks `encode` on `input`, the size is the length of its output.
+    pub fn encode<I: ?Sized, O: AsRef<[u8]>>(
+        &mut self,
+        name: &str,
+        input: &I,
+        encode: fn(&I) -> O,
+    ) {
+        let size = encode(input).as_ref().len();
+        self.bench(name, input, size, |b, input| b.iter(|| encode(input)));
+    }
+
+    /// Benchmarks `f` on the encoded `data`.
+    pub fn decode<D: AsRef<[u8]>>(
+        &mut self,
+        name: &str,
+        data: D,
+        f: impl FnMut(&mut Bencher<WallTime>, &D),
+    ) {
+        let size = data.as_ref().len();
+        self.bench(name, &data, size, f);
+    }
+}
+
+/// Hashes an encoding the way a commitment to it would.
+pub fn sha256(data: impl AsRef<[u8]>) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+pub fn header(c: &mut Criterion, name: &str, mut f: impl FnMut(&mut Sweep, &Header)) {
+    let mut group = c.benchmark_group(name);
+    let header = Header::random();
+    f(
+        &mut Sweep {
+            group: &mut group,
+            parameter: None,
+            bytes: true,
+        },
+        &header,
+    );
+    group.finish();
+}
+
+pub fn block(c: &mut Criterion, name: &str, mut f: impl FnMut(&mut Sweep, &Block)) {
+    let mut group = c.benchmark_group(format!("{}/transactions", name));
+    for &transactions in TRANSACTIONS.iter() {
+        let block = Block::random(transactions, DEFAULT_IO_SIZE);
+        group.throughput(Throughput::Elements(transactions as u64));
+        group.sample_size(if transactions >= LARGE_TRANSACTIONS {
+            LARGE_SAMPLE_SIZE
+        } else {
+            SAMPLE_SIZE
+        });
+        f(
+            &mut Sweep {
+                group: &mut group,
+                parameter: Some(transactions.to_string()),
+                bytes: false,
+            },
+            &block,
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group(format!("{}/io_size", name));
+    for &io_size in IO_SIZES.iter() {
+        let block = Block::random(DEFAULT_TRANSACTIONS, io_size);
+        f(
+            &mut Sweep {
+                group: &mut group,
+                parameter: Some(io_size.to_string()),
+                bytes: true,
+            },
+            &block,
+        );
+    }
+    group.finish();
+}
@@ -1,20 +1,147 @@
-use criterion::{criterion_group, criterion_main, Criterion, Fun};
+mod common;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+#[cfg(feature = "quick-protobuf")]
+use quick_protobuf::{BytesReader, MessageRead};
+#[cfg(feature = "quick-protobuf")]
+use serde_bench::bench_quick_protobuf::Block as QuickBlock;
 use serde_bench::Block;
 
 fn bench(c: &mut Criterion) {
-    let flatbuffers = Fun::new("flatbuffers", |b, data: &[Vec<u8>; 3]| {
-        b.iter(|| Block::from_flatbuffers(&data[0]))
-    });
-    let protobuf = Fun::new("protobuf", |b, data: &[Vec<u8>; 3]| {
-        b.iter(|| Block::from_protobuf(&data[1]))
-    });
-    let molecule = Fun::new("molecule", |b, data: &[Vec<u8>; 3]| {
-        b.iter(|| Block::from_molecule(&data[2]))
+    common::block(c, "deserialize_block", |s, block| {
+        #[cfg(feature = "flatbuffers")]
+        s.decode("flatbuffers", block.to_flatbuffers(), |b, data| {
+            b.iter(|| Block::from_flatbuffers(data))
+        });
+        #[cfg(feature = "protobuf")]
+        s.decode("protobuf", block.to_protobuf(), |b, data| {
+            b.iter(|| Block::from_protobuf(data))
+        });
+        #[cfg(feature = "molecule")]
+        s.decode("molecule", block.to_molecule(), |b, data| {
+            b.iter(|| Block::from_molecule(data))
+        });
+        #[cfg(feature = "scale")]
+        s.decode("scale", block.to_scale(), |b, data| {
+            b.iter(|| Block::from_scale(data))
+        });
+        #[cfg(feature = "borsh")]
+        s.decode("borsh", block.to_borsh(), |b, data| {
+            b.iter(|| Block::from_borsh(data))
+        });
+        #[cfg(feature = "ssz")]
+        s.decode("ssz", block.to_ssz(), |b, data| {
+            b.iter(|| Block::from_ssz(data))
+        });
+        #[cfg(feature = "rkyv")]
+        s.decode("rkyv", block.to_rkyv(), |b, bytes| {
+            b.iter(|| Block::from_rkyv(bytes))
+        });
+        #[cfg(feature = "rkyv")]
+        s.decode("rkyv_unchecked", block.to_rkyv(), |b, bytes| {
+            b.iter(|| unsafe { Block::from_rkyv_unchecked(bytes) })
+        });
+        #[cfg(feature = "prost")]
+        s.decode("prost", block.to_prost(), |b, data| {
+            b.iter(|| Block::from_prost(data))
+        });
+        #[cfg(feature = "quick-protobuf")]
+        s.decode("quick_protobuf", block.to_quick_protobuf(), |b, data| {
+            b.iter(|| Block::from_quick_protobuf(data))
+        });
+        #[cfg(feature = "quick-protobuf")]
+        s.decode(
+            "quick_protobuf_borrowed",
+            block.to_quick_protobuf(),
+            |b, data| {
+                b.iter(|| {
+                    QuickBlock::from_reader(&mut BytesReader::from_bytes(data), data).unwrap()
+                })
+            },
+        );
+        #[cfg(feature = "avro")]
+        s.decode("avro", block.to_avro(), |b, data| {
+            b.iter(|| Block::from_avro(data))
+        });
+        #[cfg(feature = "avro")]
+        s.decode("avro_resolved", block.to_avro(), |b, data| {
+            b.iter(|| Block::from_avro_resolved(data))
+        });
+        #[cfg(feature = "avro")]
+        s.decode("avro_container", block.to_avro_container(), |b, data| {
+            b.iter(|| Block::from_avro_container(data))
+        });
+        #[cfg(feature = "thrift")]
+        s.decode("thrift_binary", block.to_thrift_binary(), |b, data| {
+            b.iter(|| Block::from_thrift_binary(data))
+        });
+        #[cfg(feature = "thrift")]
+        s.decode("thrift_compact", block.to_thrift_compact(), |b, data| {
+            b.iter(|| Block::from_thrift_compact(data))
+        });
+        #[cfg(feature = "json")]
+        s.decode("json", block.to_json(), |b, data| {
+            b.iter(|| Block::from_json(data))
+        });
+        #[cfg(feature = "json")]
+        s.decode("json_simd", block.to_json(), |b, data| {
+            b.iter(|| Block::from_json_simd(data))
+        });
+        #[cfg(feature = "bcs")]
+        s.decode("bcs", block.to_bcs(), |b, data| {
+            b.iter(|| Block::from_bcs(data))
+        });
+        #[cfg(feature = "postcard")]
+        s.decode("postcard", block.to_postcard(), |b, data| {
+            b.iter(|| Block::from_postcard(data))
+        });
+        #[cfg(feature = "der")]
+        s.decode("der", block.to_der(), |b, data| {
+            b.iter(|| Block::from_der(data))
+        });
+        #[cfg(feature = "xdr")]
+        s.decode("xdr", block.to_xdr(), |b, data| {
+            b.iter(|| Block::from_xdr(data))
+        });
+        #[cfg(feature = "raw")]
+        s.decode("raw", block.to_raw(), |b, data| {
+            b.iter(|| Block::from_raw(data))
+        });
+        #[cfg(feature = "flatbuffers")]
+        s.decode(
+            "flatbuffers_struct",
+            block.to_flatbuffers_struct(),
+            |b, data| b.iter(|| Block::from_flatbuffers_struct(data)),
+        );
+        #[cfg(feature = "molecule")]
+        s.decode("molecule_struct", block.to_molecule_struct(), |b, data| {
+            b.iter(|| Block::from_molecule_struct(data))
+        });
+        #[cfg(feature = "prost")]
+        s.decode("prost_fixed", block.to_prost_fixed(), |b, data| {
+            b.iter(|| Block::from_prost_fixed(data))
+        });
+        #[cfg(feature = "flexbuffers")]
+        s.decode("flexbuffers", block.to_flexbuffers(), |b, data| {
+            b.iter(|| Block::from_flexbuffers(data))
+        });
+        #[cfg(feature = "bitcoin")]
+        s.decode("bitcoin", block.to_bitcoin(), |b, data| {
+            b.iter(|| Block::from_bitcoin(data))
+        });
+        #[cfg(feature = "bitcode")]
+        s.decode("bitcode", block.to_bitcode(), |b, data| {
+            b.iter(|| Block::from_bitcode(data))
+        });
+        #[cfg(feature = "bson")]
+        s.decode("bson", block.to_bson(), |b, data| {
+            b.iter(|| Block::from_bson(data))
+        });
+        #[cfg(feature = "sbe")]
+        s.decode("sbe", block.to_sbe(), |b, data| {
+            b.iter(|| Block::from_sbe(data))
+        });
     });
-    let functions = vec![flatbuffers, protobuf, molecule];
-    let block = Block::random(100, 3);
-    let data = [block.to_flatbuffers(), block.to_protobuf(), block.to_molecule()];
-    c.bench_functions("deserialize_block", functions, data);
 }
 
 criterion_group!(benches, bench);
@@ -1,20 +1,143 @@
-use criterion::{criterion_group, criterion_main, Criterion, Fun};
+mod common;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+#[cfg(feature = "quick-protobuf")]
+use quick_protobuf::{BytesReader, MessageRead};
+#[cfg(feature = "quick-protobuf")]
+use serde_bench::bench_quick_protobuf::Header as QuickHeader;
 use serde_bench::Header;
 
 fn bench(c: &mut Criterion) {
-    let flatbuffers = Fun::new("flatbuffers", |b, data: &[Vec<u8>; 3]| {
-        b.iter(|| Header::from_flatbuffers(&data[0]))
-    });
-    let protobuf = Fun::new("protobuf", |b, data: &[Vec<u8>; 3]| {
-        b.iter(|| Header::from_protobuf(&data[1]))
-    });
-    let molecule = Fun::new("molecule", |b, data: &[Vec<u8>; 3]| {
-        b.iter(|| Header::from_molecule(&data[2]))
+    common::header(c, "deserialize_header", |s, header| {
+        #[cfg(feature = "flatbuffers")]
+        s.decode("flatbuffers", header.to_flatbuffers(), |b, data| {
+            b.iter(|| Header::from_flatbuffers(data))
+        });
+        #[cfg(feature = "protobuf")]
+        s.decode("protobuf", header.to_protobuf(), |b, data| {
+            b.iter(|| Header::from_protobuf(data))
+        });
+        #[cfg(feature = "molecule")]
+        s.decode("molecule", header.to_molecule(), |b, data| {
+            b.iter(|| Header::from_molecule(data))
+        });
+        #[cfg(feature = "scale")]
+        s.decode("scale", header.to_scale(), |b, data| {
+            b.iter(|| Header::from_scale(data))
+        });
+        #[cfg(feature = "borsh")]
+        s.decode("borsh", header.to_borsh(), |b, data| {
+            b.iter(|| Header::from_borsh(data))
+        });
+        #[cfg(feature = "ssz")]
+        s.decode("ssz", header.to_ssz(), |b, data| {
+            b.iter(|| Header::from_ssz(data))
+        });
+        #[cfg(feature = "rkyv")]
+        s.decode("rkyv", header.to_rkyv(), |b, bytes| {
+            b.iter(|| Header::from_rkyv(bytes))
+        });
+        #[cfg(feature = "rkyv")]
+        s.decode("rkyv_unchecked", header.to_rkyv(), |b, bytes| {
+            b.iter(|| unsafe { Header::from_rkyv_unchecked(bytes) })
+        });
+        #[cfg(feature = "prost")]
+        s.decode("prost", header.to_prost(), |b, data| {
+            b.iter(|| Header::from_prost(data))
+        });
+        #[cfg(feature = "quick-protobuf")]
+        s.decode("quick_protobuf", header.to_quick_protobuf(), |b, data| {
+            b.iter(|| Header::from_quick_protobuf(data))
+        });
+        #[cfg(feature = "quick-protobuf")]
+        s.decode(
+            "quick_protobuf_borrowed",
+            header.to_quick_protobuf(),
+            |b, data| {
+                b.iter(|| {
+                    QuickHeader::from_reader(&mut BytesReader::from_bytes(data), data).unwrap()
+                })
+            },
+        );
+        #[cfg(feature = "avro")]
+        s.decode("avro", header.to_avro(), |b, data| {
+            b.iter(|| Header::from_avro(data))
+        });
+        #[cfg(feature = "thrift")]
+        s.decode("thrift_binary", header.to_thrift_binary(), |b, data| {
+            b.iter(|| Header::from_thrift_binary(data))
+        });
+        #[cfg(feature = "thrift")]
+        s.decode("thrift_compact", header.to_thrift_compact(), |b, data| {
+            b.iter(|| Header::from_thrift_compact(data))
+        });
+        #[cfg(feature = "json")]
+        s.decode("json", header.to_json(), |b, data| {
+            b.iter(|| Header::from_json(data))
+        });
+        #[cfg(feature = "json")]
+        s.decode("json_simd", header.to_json(), |b, data| {
+            b.iter(|| Header::from_json_simd(data))
+        });
+        #[cfg(feature = "bcs")]
+        s.decode("bcs", header.to_bcs(), |b, data| {
+            b.iter(|| Header::from_bcs(data))
+        });
+        #[cfg(feature = "postcard")]
+        s.decode("postcard", header.to_postcard(), |b, data| {
+            b.iter(|| Header::from_postcard(data))
+        });
+        #[cfg(feature = "postcard")]
+        s.decode("postcard_heapless", header.to_postcard(), |b, data| {
+            b.iter(|| Header::from_postcard_heapless(data))
+        });
+        #[cfg(feature = "der")]
+        s.decode("der", header.to_der(), |b, data| {
+            b.iter(|| Header::from_der(data))
+        });
+        #[cfg(feature = "xdr")]
+        s.decode("xdr", header.to_xdr(), |b, data| {
+            b.iter(|| Header::from_xdr(data))
+        });
+        #[cfg(feature = "raw")]
+        s.decode("raw", header.to_raw(), |b, data| {
+            b.iter(|| Header::from_raw(data))
+        });
+        #[cfg(feature = "flatbuffers")]
+        s.decode(
+            "flatbuffers_struct",
+            header.to_flatbuffers_struct(),
+            |b, data| b.iter(|| Header::from_flatbuffers_struct(data)),
+        );
+        #[cfg(feature = "molecule")]
+        s.decode("molecule_struct", header.to_molecule_struct(), |b, data| {
+            b.iter(|| Header::from_molecule_struct(data))
+        });
+        #[cfg(feature = "prost")]
+        s.decode("prost_fixed", header.to_prost_fixed(), |b, data| {
+            b.iter(|| Header::from_prost_fixed(data))
+        });
+        #[cfg(feature = "flexbuffers")]
+        s.decode("flexbuffers", header.to_flexbuffers(), |b, data| {
+            b.iter(|| Header::from_flexbuffers(data))
+        });
+        #[cfg(feature = "bitcoin")]
+        s.decode("bitcoin", header.to_bitcoin(), |b, data| {
+            b.iter(|| Header::from_bitcoin(data))
+        });
+        #[cfg(feature = "bitcode")]
+        s.decode("bitcode", header.to_bitcode(), |b, data| {
+            b.iter(|| Header::from_bitcode(data))
+        });
+        #[cfg(feature = "bson")]
+        s.decode("bson", header.to_bson(), |b, data| {
+            b.iter(|| Header::from_bson(data))
+        });
+        #[cfg(feature = "sbe")]
+        s.decode("sbe", header.to_sbe(), |b, data| {
+            b.iter(|| Header::from_sbe(data))
+        });
     });
-    let functions = vec![flatbuffers, protobuf, molecule];
-    let header = Header::random();
-    let data = [header.to_flatbuffers(), header.to_protobuf(), header.to_molecule()];
-    c.bench_functions("deserialize_header", functions, data);
 }
 
 criterion_group!(benches, bench);
@@ -0,0 +1,141 @@
+mod common;
+
+// Only the formats that can be read in place are benched here.
+#[cfg(any(
+    feature = "flatbuffers",
+    feature = "flexbuffers",
+    feature = "molecule",
+    feature = "raw",
+    feature = "rkyv"
+))]
+mod bench {
+    use super::common;
+    use criterion::{criterion_group, Criterion};
+    #[cfg(feature = "flatbuffers")]
+    use flatbuffers::get_root;
+    #[cfg(feature = "molecule")]
+    use molecule::prelude::Reader;
+    #[cfg(feature = "flatbuffers")]
+    use serde_bench::bench_flatbuffers::Block as FbsBlock;
+    #[cfg(feature = "flatbuffers")]
+    use serde_bench::bench_flatbuffers_struct::Block as FbsStructBlock;
+    #[cfg(feature = "molecule")]
+    use serde_bench::bench_molecule::BlockReader as MolBlockReader;
+    #[cfg(feature = "molecule")]
+    use serde_bench::bench_molecule_struct::BlockReader as MolStructBlockReader;
+    #[cfg(any(feature = "raw", feature = "rkyv"))]
+    use serde_bench::Block;
+    #[cfg(feature = "molecule")]
+    use std::convert::TryInto;
+
+    // Reads the capacity of the last output of the last transaction.
+    fn bench(c: &mut Criterion) {
+        common::block(c, "random_access_block", |s, block| {
+            #[cfg(feature = "flatbuffers")]
+            s.decode("flatbuffers", block.to_flatbuffers(), |b, data| {
+                b.iter(|| {
+                    let block = get_root::<FbsBlock>(data);
+                    let transactions = block.transactions().unwrap();
+                    let outputs = transactions.get(transactions.len() - 1).outputs().unwrap();
+                    outputs.get(outputs.len() - 1).capacity()
+                })
+            });
+            #[cfg(feature = "molecule")]
+            s.decode("molecule", block.to_molecule(), |b, data| {
+                b.iter(|| {
+                    let block = MolBlockReader::from_slice(data).unwrap();
+                    let transactions = block.transactions();
+                    let outputs = transactions.get(transactions.len() - 1).unwrap().outputs();
+                    let capacity = outputs.get(outputs.len() - 1).unwrap().capacity();
+                    u64::from_le_bytes(capacity.as_slice().try_into().unwrap())
+                })
+            });
+            #[cfg(feature = "rkyv")]
+            s.decode("rkyv", block.to_rkyv(), |b, bytes| {
+                b.iter(|| {
+                    let block = rkyv::check_archived_root::<Block>(bytes).unwrap();
+                    let outputs = &block.transactions.last().unwrap().outputs;
+                    outputs.last().unwrap().capacity
+                })
+            });
+            #[cfg(feature = "rkyv")]
+            s.decode("rkyv_unchecked", block.to_rkyv(), |b, bytes| {
+                b.iter(|| {
+                    let block = unsafe { rkyv::archived_root::<Block>(bytes) };
+                    let outputs = &block.transactions.last().unwrap().outputs;
+                    outputs.last().unwrap().capacity
+                })
+            });
+            // The raw layout has no offsets, so it has to decode the whole block.
+            #[cfg(feature = "raw")]
+            s.decode("raw", block.to_raw(), |b, data| {
+                b.iter(|| {
+                    let block = Block::from_raw(data);
+                    let outputs = &block.transactions.last().unwrap().outputs;
+                    outputs.last().unwrap().capacity
+                })
+            });
+            #[cfg(feature = "flatbuffers")]
+            s.decode(
+                "flatbuffers_struct",
+                block.to_flatbuffers_struct(),
+                |b, data| {
+                    b.iter(|| {
+                        let block = get_root::<FbsStructBlock>(data);
+                        let transactions = block.transactions().unwrap();
+                        let outputs = transactions.get(transactions.len() - 1).outputs().unwrap();
+                        outputs.get(outputs.len() - 1).capacity()
+                    })
+                },
+            );
+            #[cfg(feature = "molecule")]
+            s.decode("molecule_struct", block.to_molecule_struct(), |b, data| {
+                b.iter(|| {
+                    let block = MolStructBlockReader::from_slice(data).unwrap();
+                    let transactions = block.transactions();
+                    let outputs = transactions.get(transactions.len() - 1).unwrap().outputs();
+                    let capacity = outputs.get(outputs.len() - 1).unwrap().capacity();
+                    u64::from_le_bytes(capacity.as_slice().try_into().unwrap())
+                })
+            });
+            #[cfg(feature = "flexbuffers")]
+            s.decode("flexbuffers", block.to_flexbuffers(), |b, data| {
+                b.iter(|| {
+                    let block = flexbuffers::Reader::get_root(&data[..]).unwrap();
+                    let transactions = block.as_map().idx("transactions").as_vector();
+                    let outputs = transactions
+                        .idx(transactions.len() - 1)
+                        .as_map()
+                        .idx("outputs")
+                        .as_vector();
+                    outputs
+                        .idx(outputs.len() - 1)
+                        .as_map()
+                        .idx("capacity")
+                        .as_u64()
+                })
+            });
+        });
+    }
+
+    criterion_group!(benches, bench);
+}
+
+#[cfg(any(
+    feature = "flatbuffers",
+    feature = "flexbuffers",
+    feature = "molecule",
+    feature = "raw",
+    feature = "rkyv"
+))]
+criterion::criterion_main!(bench::benches);
+
+// With none of them enabled there is nothing to bench.
+#[cfg(not(any(
+    feature = "flatbuffers",
+    feature = "flexbuffers",
+    feature = "molecule",
+    feature = "raw",
+    feature = "rkyv"
+)))]
+fn main() {}
@@ -1,19 +1,65 @@
-use criterion::{criterion_group, criterion_main, Criterion, Fun};
+mod common;
+
+use criterion::{criterion_group, criterion_main, Criterion};
 use serde_bench::Block;
 
 fn bench(c: &mut Criterion) {
-    let flatbuffers = Fun::new("flatbuffers", |b, block: &Block| {
-        b.iter(|| block.to_flatbuffers())
-    });
-    let protobuf = Fun::new("protobuf", |b, block: &Block| {
-        b.iter(|| block.to_protobuf())
-    });
-    let molecule = Fun::new("molecule", |b, block: &Block| {
-        b.iter(|| block.to_molecule())
+    common::block(c, "serialize_block", |s, block| {
+        #[cfg(feature = "flatbuffers")]
+        s.encode("flatbuffers", block, Block::to_flatbuffers);
+        #[cfg(feature = "protobuf")]
+        s.encode("protobuf", block, Block::to_protobuf);
+        #[cfg(feature = "molecule")]
+        s.encode("molecule", block, Block::to_molecule);
+        #[cfg(feature = "scale")]
+        s.encode("scale", block, Block::to_scale);
+        #[cfg(feature = "borsh")]
+        s.encode("borsh", block, Block::to_borsh);
+        #[cfg(feature = "ssz")]
+        s.encode("ssz", block, Block::to_ssz);
+        #[cfg(feature = "rkyv")]
+        s.encode("rkyv", block, Block::to_rkyv);
+        #[cfg(feature = "prost")]
+        s.encode("prost", block, Block::to_prost);
+        #[cfg(feature = "quick-protobuf")]
+        s.encode("quick_protobuf", block, Block::to_quick_protobuf);
+        #[cfg(feature = "avro")]
+        s.encode("avro", block, Block::to_avro);
+        #[cfg(feature = "avro")]
+        s.encode("avro_container", block, Block::to_avro_container);
+        #[cfg(feature = "thrift")]
+        s.encode("thrift_binary", block, Block::to_thrift_binary);
+        #[cfg(feature = "thrift")]
+        s.encode("thrift_compact", block, Block::to_thrift_compact);
+        #[cfg(feature = "json")]
+        s.encode("json", block, Block::to_json);
+        #[cfg(feature = "bcs")]
+        s.encode("bcs", block, Block::to_bcs);
+        #[cfg(feature = "postcard")]
+        s.encode("postcard", block, Block::to_postcard);
+        #[cfg(feature = "der")]
+        s.encode("der", block, Block::to_der);
+        #[cfg(feature = "xdr")]
+        s.encode("xdr", block, Block::to_xdr);
+        #[cfg(feature = "raw")]
+        s.encode("raw", block, Block::to_raw);
+        #[cfg(feature = "flatbuffers")]
+        s.encode("flatbuffers_struct", block, Block::to_flatbuffers_struct);
+        #[cfg(feature = "molecule")]
+        s.encode("molecule_struct", block, Block::to_molecule_struct);
+        #[cfg(feature = "prost")]
+        s.encode("prost_fixed", block, Block::to_prost_fixed);
+        #[cfg(feature = "flexbuffers")]
+        s.encode("flexbuffers", block, Block::to_flexbuffers);
+        #[cfg(feature = "bitcoin")]
+        s.encode("bitcoin", block, Block::to_bitcoin);
+        #[cfg(feature = "bitcode")]
+        s.encode("bitcode", block, Block::to_bitcode);
+        #[cfg(feature = "bson")]
+        s.encode("bson", block, Block::to_bson);
+        #[cfg(feature = "sbe")]
+        s.encode("sbe", block, Block::to_sbe);
     });
-    let functions = vec![flatbuffers, protobuf, molecule];
-    let block = Block::random(100, 3);
-    c.bench_functions("serialize_block", functions, block);
 }
 
 criterion_group!(benches, bench);
@@ -1,13 +1,63 @@
-use criterion::{criterion_group, criterion_main, Criterion, Fun};
+mod common;
+
+use criterion::{criterion_group, criterion_main, Criterion};
 use serde_bench::Header;
 
 fn bench(c: &mut Criterion) {
-    let flatbuffers = Fun::new("flatbuffers", |b, h: &Header| b.iter(|| h.to_flatbuffers()));
-    let protobuf = Fun::new("protobuf", |b, h: &Header| b.iter(|| h.to_protobuf()));
-    let molecule = Fun::new("molecule", |b, h: &Header| b.iter(|| h.to_molecule()));
-    let functions = vec![flatbuffers, protobuf, molecule];
-    let header = Header::random();
-    c.bench_functions("serialize_header", functions, header);
+    common::header(c, "serialize_header", |s, header| {
+        #[cfg(feature = "flatbuffers")]
+        s.encode("flatbuffers", header, Header::to_flatbuffers);
+        #[cfg(feature = "protobuf")]
+        s.encode("protobuf", header, Header::to_protobuf);
+        #[cfg(feature = "molecule")]
+        s.encode("molecule", header, Header::to_molecule);
+        #[cfg(feature = "scale")]
+        s.encode("scale", header, Header::to_scale);
+        #[cfg(feature = "borsh")]
+        s.encode("borsh", header, Header::to_borsh);
+        #[cfg(feature = "ssz")]
+        s.encode("ssz", header, Header::to_ssz);
+        #[cfg(feature = "rkyv")]
+        s.encode("rkyv", header, Header::to_rkyv);
+        #[cfg(feature = "prost")]
+        s.encode("prost", header, Header::to_prost);
+        #[cfg(feature = "quick-protobuf")]
+        s.encode("quick_protobuf", header, Header::to_quick_protobuf);
+        #[cfg(feature = "avro")]
+        s.encode("avro", header, Header::to_avro);
+        #[cfg(feature = "thrift")]
+        s.encode("thrift_binary", header, Header::to_thrift_binary);
+        #[cfg(feature = "thrift")]
+        s.encode("thrift_compact", header, Header::to_thrift_compact);
+        #[cfg(feature = "json")]
+        s.encode("json", header, Header::to_json);
+        #[cfg(feature = "bcs")]
+        s.encode("bcs", header, Header::to_bcs);
+        #[cfg(feature = "postcard")]
+        s.encode("postcard", header, Header::to_postcard);
+        #[cfg(feature = "der")]
+        s.encode("der", header, Header::to_der);
+        #[cfg(feature = "xdr")]
+        s.encode("xdr", header, Header::to_xdr);
+        #[cfg(feature = "raw")]
+        s.encode("raw", header, Header::to_raw);
+        #[cfg(feature = "flatbuffers")]
+        s.encode("flatbuffers_struct", header, Header::to_flatbuffers_struct);
+        #[cfg(feature = "molecule")]
+        s.encode("molecule_struct", header, Header::to_molecule_struct);
+        #[cfg(feature = "prost")]
+        s.encode("prost_fixed", header, Header::to_prost_fixed);
+        #[cfg(feature = "flexbuffers")]
+        s.encode("flexbuffers", header, Header::to_flexbuffers);
+        #[cfg(feature = "bitcoin")]
+        s.encode("bitcoin", header, Header::to_bitcoin);
+        #[cfg(feature = "bitcode")]
+        s.encode("bitcode", header, Header::to_bitcode);
+        #[cfg(feature = "bson")]
+        s.encode("bson", header, Header::to_bson);
+        #[cfg(feature = "sbe")]
+        s.encode("sbe", header, Header::to_sbe);
+    });
 }
 
 criterion_group!(benches, bench);
@@ -0,0 +1,31 @@
+//! The `no_std` side of the postcard backend: a `Header` that decodes the
+//! bytes of `serde_bench::bench_postcard::Header` into fixed-capacity
+//! buffers, so decoding it does not allocate.
+
+#![no_std]
+
+use serde::{Deserialize, Serialize};
+
+/// Capacity of `HeaplessHeader::proof`. Longer proofs fail to decode.
+pub const MAX_PROOF_LENGTH: usize = 64;
+
+#[derive(Serialize, Deserialize)]
+pub struct HeaplessHeader {
+    pub version: u32,
+    pub parent_hash: [u8; 32],
+    pub timestamp: u64,
+    pub number: u64,
+    pub txs_commit: [u8; 32],
+    pub txs_proposal: [u8; 32],
+    pub difficulty: [u8; 32],
+    pub nonce: u64,
+    pub proof: heapless::Vec<u8, MAX_PROOF_LENGTH>,
+    pub cellbase_id: [u8; 32],
+    pub uncles_hash: [u8; 32],
+}
+
+impl HeaplessHeader {
+    pub fn from_postcard(data: &[u8]) -> postcard::Result<Self> {
+        postcard::from_bytes(data)
+    }
+}
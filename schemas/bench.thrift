@@ -0,0 +1,64 @@
+// Thrift has no unsigned integers, `uint32`/`uint64` fields are stored as
+// `i32`/`i64` holding the same bits.
+
+union SyncPayload {
+    1: GetHeaders   get_headers
+    2: Headers      headers
+}
+
+struct SyncMessage {
+    1: required SyncPayload     payload
+}
+
+struct GetHeaders {
+    1: required i32             version
+    2: required list<binary>    block_locator_hashes
+    3: required binary          hash_stop
+}
+
+struct Headers {
+    1: required list<Header>    headers
+}
+
+struct Header {
+    1: required i32             version
+    2: required binary          parent_hash
+    3: required i64             timestamp
+    4: required i64             number
+    5: required binary          txs_commit
+    6: required binary          txs_proposal
+    7: required binary          difficulty
+    8: required i64             nonce
+    9: required binary          proof
+    10: required binary         cellbase_id
+    11: required binary         uncles_hash
+}
+
+struct Block {
+    1: required Header              header
+    2: required list<Transaction>   transactions
+}
+
+struct Transaction {
+    1: required i32                 version
+    2: required list<OutPoint>      deps
+    3: required list<CellInput>     inputs
+    4: required list<CellOutput>    outputs
+}
+
+struct OutPoint {
+    1: required binary      hash
+    2: required i32         index
+}
+
+struct CellInput {
+    1: required binary      hash
+    2: required i32         index
+    3: required binary      unlock
+}
+
+struct CellOutput {
+    1: required i64         capacity
+    2: required binary      data
+    3: required binary      lock
+}
@@ -0,0 +1,73 @@
+/* XDR (RFC 4506) definition of the bench messages. */
+
+typedef opaque Byte32[32];
+typedef opaque Bytes<>;
+
+enum SyncPayloadType {
+    GET_HEADERS = 0,
+    HEADERS     = 1
+};
+
+union SyncPayload switch (SyncPayloadType type) {
+    case GET_HEADERS:
+        GetHeaders get_headers;
+    case HEADERS:
+        Headers headers;
+};
+
+struct SyncMessage {
+    SyncPayload     payload;
+};
+
+struct GetHeaders {
+    unsigned int    version;
+    Bytes           block_locator_hashes<>;
+    opaque          hash_stop<>;
+};
+
+struct Headers {
+    Header          headers<>;
+};
+
+struct Header {
+    unsigned int    version;
+    Byte32          parent_hash;
+    unsigned hyper  timestamp;
+    unsigned hyper  number;
+    Byte32          txs_commit;
+    Byte32          txs_proposal;
+    Byte32          difficulty;
+    unsigned hyper  nonce;
+    opaque          proof<>;
+    Byte32          cellbase_id;
+    Byte32          uncles_hash;
+};
+
+struct Block {
+    Header          header;
+    Transaction     transactions<>;
+};
+
+struct Transaction {
+    unsigned int    version;
+    OutPoint        deps<>;
+    CellInput       inputs<>;
+    CellOutput      outputs<>;
+};
+
+struct OutPoint {
+    Byte32          hash;
+    unsigned int    index;
+};
+
+struct CellInput {
+    Byte32          hash;
+    unsigned int    index;
+    opaque          unlock<>;
+};
+
+struct CellOutput {
+    unsigned hyper  capacity;
+    opaque          data<>;
+    Byte32          lock;
+};
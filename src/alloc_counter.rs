@@ -0,0 +1,79 @@
+//! A global allocator that counts allocations, used by the allocation bench
+//! and tests to measure the allocator pressure of each format.
+//!
+//! The library never installs it; a binary opts in with
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator = CountingAllocator;
+//! ```
+//!
+//! Counters are kept per thread, so tests running in parallel do not see each
+//! other's allocations. `alloc`, `alloc_zeroed` and `realloc` each count as one
+//! allocation of the requested size; freeing memory is not counted.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    static COUNT: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+}
+
+pub struct CountingAllocator;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+}
+
+impl Allocations {
+    fn current() -> Self {
+        Allocations {
+            count: COUNT.with(Cell::get),
+            bytes: BYTES.with(Cell::get),
+        }
+    }
+}
+
+/// Runs `f` and returns its result with the allocations it made on this
+/// thread. Only meaningful when `CountingAllocator` is the global allocator.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Allocations) {
+    let before = Allocations::current();
+    let result = f();
+    let after = Allocations::current();
+    let allocations = Allocations {
+        count: after.count - before.count,
+        bytes: after.bytes - before.bytes,
+    };
+    (result, allocations)
+}
+
+fn record(size: usize) {
+    // `try_with` fails once the thread's locals are destroyed; allocations
+    // made that late are not interesting to anyone.
+    let _ = COUNT.try_with(|count| count.set(count.get() + 1));
+    let _ = BYTES.try_with(|bytes| bytes.set(bytes.get() + size as u64));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
@@ -0,0 +1,89 @@
+//! Avro schemas equivalent to `schemas/bench.proto`, plus helpers to take
+//! generic `Value`s apart.
+//!
+//! Avro has no unsigned integers, so every `u32`/`u64` is stored as a `long`
+//! holding the same bits.
+
+use apache_avro::schema::RecordField;
+use apache_avro::types::Value;
+use apache_avro::Schema;
+use std::sync::OnceLock;
+
+pub struct Schemas {
+    /// The `Header` record nested in `block`.
+    pub header: Schema,
+    pub block: Schema,
+    /// `block` with an extra defaulted `epoch` field in `Header`, used to
+    /// measure reader/writer schema resolution.
+    pub block_reader: Schema,
+}
+
+pub fn schemas() -> &'static Schemas {
+    static SCHEMAS: OnceLock<Schemas> = OnceLock::new();
+    SCHEMAS.get_or_init(|| {
+        let block = Schema::parse_str(include_str!("../schemas/bench.avsc")).unwrap();
+        Schemas {
+            header: field(&block, "header").schema.clone(),
+            block_reader: Schema::parse_str(include_str!("../schemas/bench_reader.avsc")).unwrap(),
+            block,
+        }
+    })
+}
+
+/// Returns the field `name` of a record schema.
+pub fn field<'a>(schema: &'a Schema, name: &str) -> &'a RecordField {
+    match schema {
+        Schema::Record(record) => &record.fields[record.lookup[name]],
+        _ => panic!("expected record schema, found {:?}", schema),
+    }
+}
+
+/// The fields of a decoded record, taken by name so that decoding does not
+/// depend on field order or trip over fields only a reader schema adds.
+pub struct Record(Vec<(String, Value)>);
+
+impl Record {
+    pub fn take(&mut self, name: &str) -> Value {
+        let position = self
+            .0
+            .iter()
+            .position(|(field, _)| field == name)
+            .unwrap_or_else(|| panic!("missing field {}", name));
+        self.0.swap_remove(position).1
+    }
+}
+
+pub fn record(value: Value) -> Record {
+    match value {
+        Value::Record(fields) => Record(fields),
+        _ => panic!("expected record, found {:?}", value),
+    }
+}
+
+pub fn long(value: Value) -> i64 {
+    match value {
+        Value::Long(long) => long,
+        _ => panic!("expected long, found {:?}", value),
+    }
+}
+
+pub fn bytes(value: Value) -> Vec<u8> {
+    match value {
+        Value::Bytes(bytes) => bytes,
+        _ => panic!("expected bytes, found {:?}", value),
+    }
+}
+
+pub fn fixed(value: Value) -> Vec<u8> {
+    match value {
+        Value::Fixed(_, bytes) => bytes,
+        _ => panic!("expected fixed, found {:?}", value),
+    }
+}
+
+pub fn array(value: Value) -> Vec<Value> {
+    match value {
+        Value::Array(items) => items,
+        _ => panic!("expected array, found {:?}", value),
+    }
+}
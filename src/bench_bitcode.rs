@@ -0,0 +1,57 @@
+//! bitcode wire types mirroring `schemas/bench.mol`.
+//!
+//! bitcode is a Rust-only codec without a schema or a stable wire format. It
+//! encodes column by column: every field of every item in a vector is packed
+//! next to the same field of its siblings, and integers are bit-packed to the
+//! smallest width that fits the whole column.
+
+use bitcode::{Decode, Encode};
+
+#[derive(Encode, Decode)]
+pub struct Header {
+    pub version: u32,
+    pub parent_hash: [u8; 32],
+    pub timestamp: u64,
+    pub number: u64,
+    pub txs_commit: [u8; 32],
+    pub txs_proposal: [u8; 32],
+    pub difficulty: [u8; 32],
+    pub nonce: u64,
+    pub proof: Vec<u8>,
+    pub cellbase_id: [u8; 32],
+    pub uncles_hash: [u8; 32],
+}
+
+#[derive(Encode, Decode)]
+pub struct Block {
+    pub header: Header,
+    pub transactions: Vec<Transaction>,
+}
+
+#[derive(Encode, Decode)]
+pub struct Transaction {
+    pub version: u32,
+    pub deps: Vec<OutPoint>,
+    pub inputs: Vec<CellInput>,
+    pub outputs: Vec<CellOutput>,
+}
+
+#[derive(Encode, Decode)]
+pub struct OutPoint {
+    pub hash: [u8; 32],
+    pub index: u32,
+}
+
+#[derive(Encode, Decode)]
+pub struct CellInput {
+    pub hash: [u8; 32],
+    pub index: u32,
+    pub unlock: Vec<u8>,
+}
+
+#[derive(Encode, Decode)]
+pub struct CellOutput {
+    pub capacity: u64,
+    pub data: Vec<u8>,
+    pub lock: [u8; 32],
+}
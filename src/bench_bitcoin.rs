@@ -0,0 +1,269 @@
+//! Bitcoin-style consensus encoding of the domain model.
+//!
+//! Integers are fixed-width little-endian and hashes are their 32 bytes, as in
+//! Bitcoin's `CTransaction`. Byte vectors and lists are prefixed with a
+//! CompactSize count: one byte below `0xfd`, otherwise a `0xfd`, `0xfe` or
+//! `0xff` marker followed by a 2, 4 or 8-byte integer. Decoding rejects
+//! CompactSize values that could have used a shorter form, counts above
+//! `MAX_SIZE` and trailing bytes, so the encoding is canonical.
+
+use crate::read::{array, take, vec, UnexpectedEof};
+
+/// Largest CompactSize accepted on decode, the same limit Bitcoin Core uses.
+pub const MAX_SIZE: u64 = 0x0200_0000;
+
+pub struct Header {
+    pub version: u32,
+    pub parent_hash: [u8; 32],
+    pub timestamp: u64,
+    pub number: u64,
+    pub txs_commit: [u8; 32],
+    pub txs_proposal: [u8; 32],
+    pub difficulty: [u8; 32],
+    pub nonce: u64,
+    pub proof: Vec<u8>,
+    pub cellbase_id: [u8; 32],
+    pub uncles_hash: [u8; 32],
+}
+
+pub struct Block {
+    pub header: Header,
+    pub transactions: Vec<Transaction>,
+}
+
+pub struct Transaction {
+    pub version: u32,
+    pub deps: Vec<OutPoint>,
+    pub inputs: Vec<CellInput>,
+    pub outputs: Vec<CellOutput>,
+}
+
+pub struct OutPoint {
+    pub hash: [u8; 32],
+    pub index: u32,
+}
+
+pub struct CellInput {
+    pub hash: [u8; 32],
+    pub index: u32,
+    pub unlock: Vec<u8>,
+}
+
+pub struct CellOutput {
+    pub capacity: u64,
+    pub data: Vec<u8>,
+    pub lock: [u8; 32],
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    UnexpectedEof,
+    NonMinimalCompactSize,
+    SizeTooLarge,
+    TrailingBytes,
+}
+
+impl From<UnexpectedEof> for Error {
+    fn from(_: UnexpectedEof) -> Self {
+        Error::UnexpectedEof
+    }
+}
+
+pub trait Encode {
+    fn encode(&self, out: &mut Vec<u8>);
+}
+
+pub trait Decode: Sized {
+    fn decode(input: &mut &[u8]) -> Result<Self, Error>;
+}
+
+pub fn to_bytes<T: Encode>(value: &T) -> Vec<u8> {
+    let mut out = Vec::new();
+    value.encode(&mut out);
+    out
+}
+
+pub fn from_bytes<T: Decode>(mut data: &[u8]) -> Result<T, Error> {
+    let value = T::decode(&mut data)?;
+    if data.is_empty() {
+        Ok(value)
+    } else {
+        Err(Error::TrailingBytes)
+    }
+}
+
+impl Encode for Header {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.version.to_le_bytes());
+        out.extend_from_slice(&self.parent_hash);
+        out.extend_from_slice(&self.timestamp.to_le_bytes());
+        out.extend_from_slice(&self.number.to_le_bytes());
+        out.extend_from_slice(&self.txs_commit);
+        out.extend_from_slice(&self.txs_proposal);
+        out.extend_from_slice(&self.difficulty);
+        out.extend_from_slice(&self.nonce.to_le_bytes());
+        encode_bytes(&self.proof, out);
+        out.extend_from_slice(&self.cellbase_id);
+        out.extend_from_slice(&self.uncles_hash);
+    }
+}
+
+impl Decode for Header {
+    fn decode(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(Header {
+            version: u32::from_le_bytes(array(input)?),
+            parent_hash: array(input)?,
+            timestamp: u64::from_le_bytes(array(input)?),
+            number: u64::from_le_bytes(array(input)?),
+            txs_commit: array(input)?,
+            txs_proposal: array(input)?,
+            difficulty: array(input)?,
+            nonce: u64::from_le_bytes(array(input)?),
+            proof: decode_bytes(input)?,
+            cellbase_id: array(input)?,
+            uncles_hash: array(input)?,
+        })
+    }
+}
+
+impl Encode for Block {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.header.encode(out);
+        encode_vec(&self.transactions, out);
+    }
+}
+
+impl Decode for Block {
+    fn decode(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(Block {
+            header: Header::decode(input)?,
+            transactions: decode_vec(input)?,
+        })
+    }
+}
+
+impl Encode for Transaction {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.version.to_le_bytes());
+        encode_vec(&self.deps, out);
+        encode_vec(&self.inputs, out);
+        encode_vec(&self.outputs, out);
+    }
+}
+
+impl Decode for Transaction {
+    fn decode(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(Transaction {
+            version: u32::from_le_bytes(array(input)?),
+            deps: decode_vec(input)?,
+            inputs: decode_vec(input)?,
+            outputs: decode_vec(input)?,
+        })
+    }
+}
+
+impl Encode for OutPoint {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.hash);
+        out.extend_from_slice(&self.index.to_le_bytes());
+    }
+}
+
+impl Decode for OutPoint {
+    fn decode(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(OutPoint {
+            hash: array(input)?,
+            index: u32::from_le_bytes(array(input)?),
+        })
+    }
+}
+
+impl Encode for CellInput {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.hash);
+        out.extend_from_slice(&self.index.to_le_bytes());
+        encode_bytes(&self.unlock, out);
+    }
+}
+
+impl Decode for CellInput {
+    fn decode(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(CellInput {
+            hash: array(input)?,
+            index: u32::from_le_bytes(array(input)?),
+            unlock: decode_bytes(input)?,
+        })
+    }
+}
+
+impl Encode for CellOutput {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.capacity.to_le_bytes());
+        encode_bytes(&self.data, out);
+        out.extend_from_slice(&self.lock);
+    }
+}
+
+impl Decode for CellOutput {
+    fn decode(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(CellOutput {
+            capacity: u64::from_le_bytes(array(input)?),
+            data: decode_bytes(input)?,
+            lock: array(input)?,
+        })
+    }
+}
+
+pub fn encode_compact_size(size: u64, out: &mut Vec<u8>) {
+    if size < 0xfd {
+        out.push(size as u8);
+    } else if size <= 0xffff {
+        out.push(0xfd);
+        out.extend_from_slice(&(size as u16).to_le_bytes());
+    } else if size <= 0xffff_ffff {
+        out.push(0xfe);
+        out.extend_from_slice(&(size as u32).to_le_bytes());
+    } else {
+        out.push(0xff);
+        out.extend_from_slice(&size.to_le_bytes());
+    }
+}
+
+pub fn decode_compact_size(input: &mut &[u8]) -> Result<u64, Error> {
+    let (size, min) = match take(input, 1)?[0] {
+        0xfd => (u16::from_le_bytes(array(input)?) as u64, 0xfd),
+        0xfe => (u32::from_le_bytes(array(input)?) as u64, 0x1_0000),
+        0xff => (u64::from_le_bytes(array(input)?), 0x1_0000_0000),
+        byte => (byte as u64, 0),
+    };
+    if size < min {
+        return Err(Error::NonMinimalCompactSize);
+    }
+    if size > MAX_SIZE {
+        return Err(Error::SizeTooLarge);
+    }
+    Ok(size)
+}
+
+fn encode_bytes(bytes: &[u8], out: &mut Vec<u8>) {
+    encode_compact_size(bytes.len() as u64, out);
+    out.extend_from_slice(bytes);
+}
+
+fn encode_vec<T: Encode>(items: &[T], out: &mut Vec<u8>) {
+    encode_compact_size(items.len() as u64, out);
+    for item in items {
+        item.encode(out);
+    }
+}
+
+fn decode_bytes(input: &mut &[u8]) -> Result<Vec<u8>, Error> {
+    let len = decode_compact_size(input)? as usize;
+    Ok(take(input, len)?.to_vec())
+}
+
+fn decode_vec<T: Decode>(input: &mut &[u8]) -> Result<Vec<T>, Error> {
+    let len = decode_compact_size(input)? as usize;
+    // Every item takes at least 4 bytes.
+    vec(input, len, 4, T::decode)
+}
@@ -0,0 +1,55 @@
+//! Borsh wire types mirroring `schemas/bench.mol`.
+//!
+//! Integers are fixed-width little-endian, hashes are fixed `[u8; 32]` arrays
+//! and vectors carry a `u32` length prefix.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Header {
+    pub version: u32,
+    pub parent_hash: [u8; 32],
+    pub timestamp: u64,
+    pub number: u64,
+    pub txs_commit: [u8; 32],
+    pub txs_proposal: [u8; 32],
+    pub difficulty: [u8; 32],
+    pub nonce: u64,
+    pub proof: Vec<u8>,
+    pub cellbase_id: [u8; 32],
+    pub uncles_hash: [u8; 32],
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Block {
+    pub header: Header,
+    pub transactions: Vec<Transaction>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Transaction {
+    pub version: u32,
+    pub deps: Vec<OutPoint>,
+    pub inputs: Vec<CellInput>,
+    pub outputs: Vec<CellOutput>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct OutPoint {
+    pub hash: [u8; 32],
+    pub index: u32,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct CellInput {
+    pub hash: [u8; 32],
+    pub index: u32,
+    pub unlock: Vec<u8>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct CellOutput {
+    pub capacity: u64,
+    pub data: Vec<u8>,
+    pub lock: [u8; 32],
+}
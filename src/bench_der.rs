@@ -0,0 +1,86 @@
+//! DER types for `schemas/bench.asn1`.
+//!
+//! Integers are minimal `INTEGER`s, hashes are `OCTET STRING (SIZE (32))` and
+//! lists are `SEQUENCE OF`. DER leaves no encoding choices, so equal values
+//! always produce equal bytes.
+
+use der::asn1::{OctetString, OctetStringRef};
+use der::{DecodeValue, EncodeValue, FixedTag, Length, Reader, Sequence, Tag, Writer};
+use std::convert::TryInto;
+
+#[derive(Sequence)]
+pub struct Header {
+    pub version: u32,
+    pub parent_hash: Byte32,
+    pub timestamp: u64,
+    pub number: u64,
+    pub txs_commit: Byte32,
+    pub txs_proposal: Byte32,
+    pub difficulty: Byte32,
+    pub nonce: u64,
+    pub proof: OctetString,
+    pub cellbase_id: Byte32,
+    pub uncles_hash: Byte32,
+}
+
+#[derive(Sequence)]
+pub struct Block {
+    pub header: Header,
+    pub transactions: Vec<Transaction>,
+}
+
+#[derive(Sequence)]
+pub struct Transaction {
+    pub version: u32,
+    pub deps: Vec<OutPoint>,
+    pub inputs: Vec<CellInput>,
+    pub outputs: Vec<CellOutput>,
+}
+
+#[derive(Sequence)]
+pub struct OutPoint {
+    pub hash: Byte32,
+    pub index: u32,
+}
+
+#[derive(Sequence)]
+pub struct CellInput {
+    pub hash: Byte32,
+    pub index: u32,
+    pub unlock: OctetString,
+}
+
+#[derive(Sequence)]
+pub struct CellOutput {
+    pub capacity: u64,
+    pub data: OctetString,
+    pub lock: Byte32,
+}
+
+/// `OCTET STRING (SIZE (32))`, decoding fails on any other length.
+pub struct Byte32(pub [u8; 32]);
+
+impl FixedTag for Byte32 {
+    const TAG: Tag = Tag::OctetString;
+}
+
+impl<'a> DecodeValue<'a> for Byte32 {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
+        let bytes = OctetStringRef::decode_value(reader, header)?;
+        bytes
+            .as_bytes()
+            .try_into()
+            .map(Byte32)
+            .map_err(|_| Self::TAG.length_error())
+    }
+}
+
+impl EncodeValue for Byte32 {
+    fn value_len(&self) -> der::Result<Length> {
+        Ok(Length::new(32))
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> der::Result<()> {
+        writer.write(&self.0)
+    }
+}
@@ -0,0 +1,813 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+use std::cmp::Ordering;
+use std::mem;
+
+extern crate flatbuffers;
+use self::flatbuffers::EndianScalar;
+
+// struct Byte32, aligned to 1
+#[repr(C, align(1))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Byte32 {
+    bytes_: [u8; 32],
+} // pub struct Byte32
+impl flatbuffers::SafeSliceAccess for Byte32 {}
+impl<'a> flatbuffers::Follow<'a> for Byte32 {
+    type Inner = &'a Byte32;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        <&'a Byte32 as flatbuffers::Follow<'a>>::follow(buf, loc)
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Byte32 {
+    type Inner = &'a Byte32;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        flatbuffers::follow_cast_ref::<Byte32>(buf, loc)
+    }
+}
+impl<'b> flatbuffers::Push for Byte32 {
+    type Output = Byte32;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(self as *const Byte32 as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+impl<'b> flatbuffers::Push for &'b Byte32 {
+    type Output = Byte32;
+
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(*self as *const Byte32 as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+
+impl Byte32 {
+    pub fn new<'a>(_bytes: &'a [u8; 32]) -> Self {
+        Byte32 { bytes_: *_bytes }
+    }
+    pub fn bytes<'a>(&'a self) -> &'a [u8; 32] {
+        &self.bytes_
+    }
+}
+
+// struct OutPoint, aligned to 4
+#[repr(C, align(4))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OutPoint {
+    hash_: Byte32,
+    index_: u32,
+} // pub struct OutPoint
+impl flatbuffers::SafeSliceAccess for OutPoint {}
+impl<'a> flatbuffers::Follow<'a> for OutPoint {
+    type Inner = &'a OutPoint;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        <&'a OutPoint as flatbuffers::Follow<'a>>::follow(buf, loc)
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a OutPoint {
+    type Inner = &'a OutPoint;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        flatbuffers::follow_cast_ref::<OutPoint>(buf, loc)
+    }
+}
+impl<'b> flatbuffers::Push for OutPoint {
+    type Output = OutPoint;
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(self as *const OutPoint as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+impl<'b> flatbuffers::Push for &'b OutPoint {
+    type Output = OutPoint;
+
+    #[inline]
+    fn push(&self, dst: &mut [u8], _rest: &[u8]) {
+        let src = unsafe {
+            ::std::slice::from_raw_parts(*self as *const OutPoint as *const u8, Self::size())
+        };
+        dst.copy_from_slice(src);
+    }
+}
+
+impl OutPoint {
+    pub fn new<'a>(_hash: &'a Byte32, _index: u32) -> Self {
+        OutPoint {
+            hash_: *_hash,
+            index_: _index.to_little_endian(),
+        }
+    }
+    pub fn hash<'a>(&'a self) -> &'a Byte32 {
+        &self.hash_
+    }
+    pub fn index<'a>(&'a self) -> u32 {
+        self.index_.from_little_endian()
+    }
+}
+
+pub enum HeaderOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Header<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Header<'a> {
+    type Inner = Header<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Header<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Header { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args HeaderArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Header<'bldr>> {
+        let mut builder = HeaderBuilder::new(_fbb);
+        builder.add_nonce(args.nonce);
+        builder.add_number(args.number);
+        builder.add_timestamp(args.timestamp);
+        if let Some(x) = args.uncles_hash {
+            builder.add_uncles_hash(x);
+        }
+        if let Some(x) = args.cellbase_id {
+            builder.add_cellbase_id(x);
+        }
+        if let Some(x) = args.proof {
+            builder.add_proof(x);
+        }
+        if let Some(x) = args.difficulty {
+            builder.add_difficulty(x);
+        }
+        if let Some(x) = args.txs_proposal {
+            builder.add_txs_proposal(x);
+        }
+        if let Some(x) = args.txs_commit {
+            builder.add_txs_commit(x);
+        }
+        if let Some(x) = args.parent_hash {
+            builder.add_parent_hash(x);
+        }
+        builder.add_version(args.version);
+        builder.finish()
+    }
+
+    pub const VT_VERSION: flatbuffers::VOffsetT = 4;
+    pub const VT_PARENT_HASH: flatbuffers::VOffsetT = 6;
+    pub const VT_TIMESTAMP: flatbuffers::VOffsetT = 8;
+    pub const VT_NUMBER: flatbuffers::VOffsetT = 10;
+    pub const VT_TXS_COMMIT: flatbuffers::VOffsetT = 12;
+    pub const VT_TXS_PROPOSAL: flatbuffers::VOffsetT = 14;
+    pub const VT_DIFFICULTY: flatbuffers::VOffsetT = 16;
+    pub const VT_NONCE: flatbuffers::VOffsetT = 18;
+    pub const VT_PROOF: flatbuffers::VOffsetT = 20;
+    pub const VT_CELLBASE_ID: flatbuffers::VOffsetT = 22;
+    pub const VT_UNCLES_HASH: flatbuffers::VOffsetT = 24;
+
+    #[inline]
+    pub fn version(&self) -> u32 {
+        self._tab.get::<u32>(Header::VT_VERSION, Some(0)).unwrap()
+    }
+    #[inline]
+    pub fn parent_hash(&self) -> Option<&'a Byte32> {
+        self._tab.get::<Byte32>(Header::VT_PARENT_HASH, None)
+    }
+    #[inline]
+    pub fn timestamp(&self) -> u64 {
+        self._tab.get::<u64>(Header::VT_TIMESTAMP, Some(0)).unwrap()
+    }
+    #[inline]
+    pub fn number(&self) -> u64 {
+        self._tab.get::<u64>(Header::VT_NUMBER, Some(0)).unwrap()
+    }
+    #[inline]
+    pub fn txs_commit(&self) -> Option<&'a Byte32> {
+        self._tab.get::<Byte32>(Header::VT_TXS_COMMIT, None)
+    }
+    #[inline]
+    pub fn txs_proposal(&self) -> Option<&'a Byte32> {
+        self._tab.get::<Byte32>(Header::VT_TXS_PROPOSAL, None)
+    }
+    #[inline]
+    pub fn difficulty(&self) -> Option<&'a Byte32> {
+        self._tab.get::<Byte32>(Header::VT_DIFFICULTY, None)
+    }
+    #[inline]
+    pub fn nonce(&self) -> u64 {
+        self._tab.get::<u64>(Header::VT_NONCE, Some(0)).unwrap()
+    }
+    #[inline]
+    pub fn proof(&self) -> Option<&'a [u8]> {
+        self._tab
+            .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                Header::VT_PROOF,
+                None,
+            )
+            .map(|v| v.safe_slice())
+    }
+    #[inline]
+    pub fn cellbase_id(&self) -> Option<&'a Byte32> {
+        self._tab.get::<Byte32>(Header::VT_CELLBASE_ID, None)
+    }
+    #[inline]
+    pub fn uncles_hash(&self) -> Option<&'a Byte32> {
+        self._tab.get::<Byte32>(Header::VT_UNCLES_HASH, None)
+    }
+}
+
+pub struct HeaderArgs<'a> {
+    pub version: u32,
+    pub parent_hash: Option<&'a Byte32>,
+    pub timestamp: u64,
+    pub number: u64,
+    pub txs_commit: Option<&'a Byte32>,
+    pub txs_proposal: Option<&'a Byte32>,
+    pub difficulty: Option<&'a Byte32>,
+    pub nonce: u64,
+    pub proof: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub cellbase_id: Option<&'a Byte32>,
+    pub uncles_hash: Option<&'a Byte32>,
+}
+impl<'a> Default for HeaderArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        HeaderArgs {
+            version: 0,
+            parent_hash: None,
+            timestamp: 0,
+            number: 0,
+            txs_commit: None,
+            txs_proposal: None,
+            difficulty: None,
+            nonce: 0,
+            proof: None,
+            cellbase_id: None,
+            uncles_hash: None,
+        }
+    }
+}
+pub struct HeaderBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> HeaderBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_version(&mut self, version: u32) {
+        self.fbb_.push_slot::<u32>(Header::VT_VERSION, version, 0);
+    }
+    #[inline]
+    pub fn add_parent_hash(&mut self, parent_hash: &'b Byte32) {
+        self.fbb_
+            .push_slot_always::<&Byte32>(Header::VT_PARENT_HASH, parent_hash);
+    }
+    #[inline]
+    pub fn add_timestamp(&mut self, timestamp: u64) {
+        self.fbb_
+            .push_slot::<u64>(Header::VT_TIMESTAMP, timestamp, 0);
+    }
+    #[inline]
+    pub fn add_number(&mut self, number: u64) {
+        self.fbb_.push_slot::<u64>(Header::VT_NUMBER, number, 0);
+    }
+    #[inline]
+    pub fn add_txs_commit(&mut self, txs_commit: &'b Byte32) {
+        self.fbb_
+            .push_slot_always::<&Byte32>(Header::VT_TXS_COMMIT, txs_commit);
+    }
+    #[inline]
+    pub fn add_txs_proposal(&mut self, txs_proposal: &'b Byte32) {
+        self.fbb_
+            .push_slot_always::<&Byte32>(Header::VT_TXS_PROPOSAL, txs_proposal);
+    }
+    #[inline]
+    pub fn add_difficulty(&mut self, difficulty: &'b Byte32) {
+        self.fbb_
+            .push_slot_always::<&Byte32>(Header::VT_DIFFICULTY, difficulty);
+    }
+    #[inline]
+    pub fn add_nonce(&mut self, nonce: u64) {
+        self.fbb_.push_slot::<u64>(Header::VT_NONCE, nonce, 0);
+    }
+    #[inline]
+    pub fn add_proof(&mut self, proof: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Header::VT_PROOF, proof);
+    }
+    #[inline]
+    pub fn add_cellbase_id(&mut self, cellbase_id: &'b Byte32) {
+        self.fbb_
+            .push_slot_always::<&Byte32>(Header::VT_CELLBASE_ID, cellbase_id);
+    }
+    #[inline]
+    pub fn add_uncles_hash(&mut self, uncles_hash: &'b Byte32) {
+        self.fbb_
+            .push_slot_always::<&Byte32>(Header::VT_UNCLES_HASH, uncles_hash);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> HeaderBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        HeaderBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<Header<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+pub enum BlockOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Block<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Block<'a> {
+    type Inner = Block<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Block<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Block { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args BlockArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Block<'bldr>> {
+        let mut builder = BlockBuilder::new(_fbb);
+        if let Some(x) = args.transactions {
+            builder.add_transactions(x);
+        }
+        if let Some(x) = args.header {
+            builder.add_header(x);
+        }
+        builder.finish()
+    }
+
+    pub const VT_HEADER: flatbuffers::VOffsetT = 4;
+    pub const VT_TRANSACTIONS: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub fn header(&self) -> Option<Header<'a>> {
+        self._tab
+            .get::<flatbuffers::ForwardsUOffset<Header<'a>>>(Block::VT_HEADER, None)
+    }
+    #[inline]
+    pub fn transactions(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Transaction<'a>>>> {
+        self._tab.get::<flatbuffers::ForwardsUOffset<
+            flatbuffers::Vector<flatbuffers::ForwardsUOffset<Transaction<'a>>>,
+        >>(Block::VT_TRANSACTIONS, None)
+    }
+}
+
+pub struct BlockArgs<'a> {
+    pub header: Option<flatbuffers::WIPOffset<Header<'a>>>,
+    pub transactions: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Transaction<'a>>>,
+        >,
+    >,
+}
+impl<'a> Default for BlockArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        BlockArgs {
+            header: None,
+            transactions: None,
+        }
+    }
+}
+pub struct BlockBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> BlockBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_header(&mut self, header: flatbuffers::WIPOffset<Header<'b>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<Header>>(Block::VT_HEADER, header);
+    }
+    #[inline]
+    pub fn add_transactions(
+        &mut self,
+        transactions: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Transaction<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Block::VT_TRANSACTIONS, transactions);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> BlockBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        BlockBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<Block<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+pub enum TransactionOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct Transaction<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Transaction<'a> {
+    type Inner = Transaction<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> Transaction<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Transaction { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args TransactionArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Transaction<'bldr>> {
+        let mut builder = TransactionBuilder::new(_fbb);
+        if let Some(x) = args.outputs {
+            builder.add_outputs(x);
+        }
+        if let Some(x) = args.inputs {
+            builder.add_inputs(x);
+        }
+        if let Some(x) = args.deps {
+            builder.add_deps(x);
+        }
+        builder.add_version(args.version);
+        builder.finish()
+    }
+
+    pub const VT_VERSION: flatbuffers::VOffsetT = 4;
+    pub const VT_DEPS: flatbuffers::VOffsetT = 6;
+    pub const VT_INPUTS: flatbuffers::VOffsetT = 8;
+    pub const VT_OUTPUTS: flatbuffers::VOffsetT = 10;
+
+    #[inline]
+    pub fn version(&self) -> u32 {
+        self._tab
+            .get::<u32>(Transaction::VT_VERSION, Some(0))
+            .unwrap()
+    }
+    #[inline]
+    pub fn deps(&self) -> Option<&'a [OutPoint]> {
+        self._tab
+            .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<OutPoint>>>(
+                Transaction::VT_DEPS,
+                None,
+            )
+            .map(|v| v.safe_slice())
+    }
+    #[inline]
+    pub fn inputs(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CellInput<'a>>>> {
+        self._tab.get::<flatbuffers::ForwardsUOffset<
+            flatbuffers::Vector<flatbuffers::ForwardsUOffset<CellInput<'a>>>,
+        >>(Transaction::VT_INPUTS, None)
+    }
+    #[inline]
+    pub fn outputs(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CellOutput<'a>>>> {
+        self._tab.get::<flatbuffers::ForwardsUOffset<
+            flatbuffers::Vector<flatbuffers::ForwardsUOffset<CellOutput<'a>>>,
+        >>(Transaction::VT_OUTPUTS, None)
+    }
+}
+
+pub struct TransactionArgs<'a> {
+    pub version: u32,
+    pub deps: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, OutPoint>>>,
+    pub inputs: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CellInput<'a>>>,
+        >,
+    >,
+    pub outputs: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<CellOutput<'a>>>,
+        >,
+    >,
+}
+impl<'a> Default for TransactionArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        TransactionArgs {
+            version: 0,
+            deps: None,
+            inputs: None,
+            outputs: None,
+        }
+    }
+}
+pub struct TransactionBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TransactionBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_version(&mut self, version: u32) {
+        self.fbb_
+            .push_slot::<u32>(Transaction::VT_VERSION, version, 0);
+    }
+    #[inline]
+    pub fn add_deps(&mut self, deps: flatbuffers::WIPOffset<flatbuffers::Vector<'b, OutPoint>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Transaction::VT_DEPS, deps);
+    }
+    #[inline]
+    pub fn add_inputs(
+        &mut self,
+        inputs: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<CellInput<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Transaction::VT_INPUTS, inputs);
+    }
+    #[inline]
+    pub fn add_outputs(
+        &mut self,
+        outputs: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<CellOutput<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Transaction::VT_OUTPUTS, outputs);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TransactionBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        TransactionBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<Transaction<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+pub enum CellInputOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct CellInput<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CellInput<'a> {
+    type Inner = CellInput<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> CellInput<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        CellInput { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args CellInputArgs<'args>,
+    ) -> flatbuffers::WIPOffset<CellInput<'bldr>> {
+        let mut builder = CellInputBuilder::new(_fbb);
+        if let Some(x) = args.unlock {
+            builder.add_unlock(x);
+        }
+        if let Some(x) = args.previous_output {
+            builder.add_previous_output(x);
+        }
+        builder.finish()
+    }
+
+    pub const VT_PREVIOUS_OUTPUT: flatbuffers::VOffsetT = 4;
+    pub const VT_UNLOCK: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub fn previous_output(&self) -> Option<&'a OutPoint> {
+        self._tab
+            .get::<OutPoint>(CellInput::VT_PREVIOUS_OUTPUT, None)
+    }
+    #[inline]
+    pub fn unlock(&self) -> Option<&'a [u8]> {
+        self._tab
+            .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                CellInput::VT_UNLOCK,
+                None,
+            )
+            .map(|v| v.safe_slice())
+    }
+}
+
+pub struct CellInputArgs<'a> {
+    pub previous_output: Option<&'a OutPoint>,
+    pub unlock: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+}
+impl<'a> Default for CellInputArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        CellInputArgs {
+            previous_output: None,
+            unlock: None,
+        }
+    }
+}
+pub struct CellInputBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CellInputBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_previous_output(&mut self, previous_output: &'b OutPoint) {
+        self.fbb_
+            .push_slot_always::<&OutPoint>(CellInput::VT_PREVIOUS_OUTPUT, previous_output);
+    }
+    #[inline]
+    pub fn add_unlock(&mut self, unlock: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CellInput::VT_UNLOCK, unlock);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CellInputBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        CellInputBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<CellInput<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+pub enum CellOutputOffset {}
+#[derive(Copy, Clone, Debug, PartialEq)]
+
+pub struct CellOutput<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CellOutput<'a> {
+    type Inner = CellOutput<'a>;
+    #[inline]
+    fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table { buf: buf, loc: loc },
+        }
+    }
+}
+
+impl<'a> CellOutput<'a> {
+    #[inline]
+    pub fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        CellOutput { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args CellOutputArgs<'args>,
+    ) -> flatbuffers::WIPOffset<CellOutput<'bldr>> {
+        let mut builder = CellOutputBuilder::new(_fbb);
+        builder.add_capacity(args.capacity);
+        if let Some(x) = args.lock {
+            builder.add_lock(x);
+        }
+        if let Some(x) = args.data {
+            builder.add_data(x);
+        }
+        builder.finish()
+    }
+
+    pub const VT_CAPACITY: flatbuffers::VOffsetT = 4;
+    pub const VT_DATA: flatbuffers::VOffsetT = 6;
+    pub const VT_LOCK: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub fn capacity(&self) -> u64 {
+        self._tab
+            .get::<u64>(CellOutput::VT_CAPACITY, Some(0))
+            .unwrap()
+    }
+    #[inline]
+    pub fn data(&self) -> Option<&'a [u8]> {
+        self._tab
+            .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(
+                CellOutput::VT_DATA,
+                None,
+            )
+            .map(|v| v.safe_slice())
+    }
+    #[inline]
+    pub fn lock(&self) -> Option<&'a Byte32> {
+        self._tab.get::<Byte32>(CellOutput::VT_LOCK, None)
+    }
+}
+
+pub struct CellOutputArgs<'a> {
+    pub capacity: u64,
+    pub data: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub lock: Option<&'a Byte32>,
+}
+impl<'a> Default for CellOutputArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        CellOutputArgs {
+            capacity: 0,
+            data: None,
+            lock: None,
+        }
+    }
+}
+pub struct CellOutputBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CellOutputBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_capacity(&mut self, capacity: u64) {
+        self.fbb_
+            .push_slot::<u64>(CellOutput::VT_CAPACITY, capacity, 0);
+    }
+    #[inline]
+    pub fn add_data(&mut self, data: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u8>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CellOutput::VT_DATA, data);
+    }
+    #[inline]
+    pub fn add_lock(&mut self, lock: &'b Byte32) {
+        self.fbb_
+            .push_slot_always::<&Byte32>(CellOutput::VT_LOCK, lock);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CellOutputBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        CellOutputBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<CellOutput<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
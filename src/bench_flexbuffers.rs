@@ -0,0 +1,69 @@
+//! FlexBuffers wire types mirroring `schemas/bench.fbs`.
+//!
+//! FlexBuffers is schemaless: structs are written as maps keyed by field
+//! name, so a reader can still look up a single field without decoding the
+//! rest. Hashes and byte vectors go through `serde_bytes` and are stored as
+//! blobs instead of vectors of integers.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct Header {
+    pub version: u32,
+    #[serde(with = "serde_bytes")]
+    pub parent_hash: [u8; 32],
+    pub timestamp: u64,
+    pub number: u64,
+    #[serde(with = "serde_bytes")]
+    pub txs_commit: [u8; 32],
+    #[serde(with = "serde_bytes")]
+    pub txs_proposal: [u8; 32],
+    #[serde(with = "serde_bytes")]
+    pub difficulty: [u8; 32],
+    pub nonce: u64,
+    #[serde(with = "serde_bytes")]
+    pub proof: Vec<u8>,
+    #[serde(with = "serde_bytes")]
+    pub cellbase_id: [u8; 32],
+    #[serde(with = "serde_bytes")]
+    pub uncles_hash: [u8; 32],
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Block {
+    pub header: Header,
+    pub transactions: Vec<Transaction>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Transaction {
+    pub version: u32,
+    pub deps: Vec<OutPoint>,
+    pub inputs: Vec<CellInput>,
+    pub outputs: Vec<CellOutput>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct OutPoint {
+    #[serde(with = "serde_bytes")]
+    pub hash: [u8; 32],
+    pub index: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CellInput {
+    #[serde(with = "serde_bytes")]
+    pub hash: [u8; 32],
+    pub index: u32,
+    #[serde(with = "serde_bytes")]
+    pub unlock: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CellOutput {
+    pub capacity: u64,
+    #[serde(with = "serde_bytes")]
+    pub data: Vec<u8>,
+    #[serde(with = "serde_bytes")]
+    pub lock: [u8; 32],
+}
@@ -0,0 +1,139 @@
+//! JSON types mirroring `schemas/bench.mol` in the CKB RPC style.
+//!
+//! Hashes, `difficulty` and byte vectors are `0x`-prefixed lowercase hex
+//! strings, integers are plain JSON numbers.
+
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str};
+
+#[derive(Serialize, Deserialize)]
+pub struct Header {
+    pub version: u32,
+    pub parent_hash: Byte32,
+    pub timestamp: u64,
+    pub number: u64,
+    pub txs_commit: Byte32,
+    pub txs_proposal: Byte32,
+    pub difficulty: Byte32,
+    pub nonce: u64,
+    pub proof: JsonBytes,
+    pub cellbase_id: Byte32,
+    pub uncles_hash: Byte32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Block {
+    pub header: Header,
+    pub transactions: Vec<Transaction>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Transaction {
+    pub version: u32,
+    pub deps: Vec<OutPoint>,
+    pub inputs: Vec<CellInput>,
+    pub outputs: Vec<CellOutput>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct OutPoint {
+    pub hash: Byte32,
+    pub index: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CellInput {
+    pub hash: Byte32,
+    pub index: u32,
+    pub unlock: JsonBytes,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CellOutput {
+    pub capacity: u64,
+    pub data: JsonBytes,
+    pub lock: Byte32,
+}
+
+/// 32 bytes as a `0x`-prefixed hex string of exactly 64 digits.
+pub struct Byte32(pub [u8; 32]);
+
+/// Arbitrary bytes as a `0x`-prefixed hex string, `"0x"` when empty.
+pub struct JsonBytes(pub Vec<u8>);
+
+impl Serialize for Byte32 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_hex(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Byte32 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(Byte32Visitor)
+    }
+}
+
+impl Serialize for JsonBytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_hex(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonBytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(JsonBytesVisitor)
+    }
+}
+
+struct Byte32Visitor;
+
+impl<'de> de::Visitor<'de> for Byte32Visitor {
+    type Value = Byte32;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a 0x-prefixed hex string with 64 digits")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let hex = strip_hex_prefix(value)?;
+        if hex.len() != 64 {
+            return Err(E::invalid_length(hex.len(), &self));
+        }
+        let mut bytes = [0u8; 32];
+        faster_hex::hex_decode(hex.as_bytes(), &mut bytes).map_err(E::custom)?;
+        Ok(Byte32(bytes))
+    }
+}
+
+struct JsonBytesVisitor;
+
+impl<'de> de::Visitor<'de> for JsonBytesVisitor {
+    type Value = JsonBytes;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a 0x-prefixed hex string with an even number of digits")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let hex = strip_hex_prefix(value)?;
+        if hex.len() % 2 != 0 {
+            return Err(E::invalid_length(hex.len(), &self));
+        }
+        let mut bytes = vec![0u8; hex.len() / 2];
+        faster_hex::hex_decode(hex.as_bytes(), &mut bytes).map_err(E::custom)?;
+        Ok(JsonBytes(bytes))
+    }
+}
+
+fn serialize_hex<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    let mut buffer = vec![0u8; 2 + bytes.len() * 2];
+    buffer[..2].copy_from_slice(b"0x");
+    faster_hex::hex_encode(bytes, &mut buffer[2..]).map_err(ser::Error::custom)?;
+    serializer.serialize_str(str::from_utf8(&buffer).map_err(ser::Error::custom)?)
+}
+
+fn strip_hex_prefix<E: de::Error>(value: &str) -> Result<&str, E> {
+    value
+        .strip_prefix("0x")
+        .ok_or_else(|| E::custom("hex string must start with 0x"))
+}
@@ -0,0 +1,60 @@
+//! postcard wire types mirroring `schemas/bench.mol`.
+//!
+//! Integers are varint-encoded, hashes are fixed `[u8; 32]` arrays and byte
+//! vectors carry a varint length prefix.
+//!
+//! `HeaplessHeader` decodes the same bytes as `Header` into fixed-capacity
+//! buffers, so decoding it does not allocate. It lives in the `no_std`
+//! crate so that embedded consumers can use it without `std`.
+
+use serde::{Deserialize, Serialize};
+pub use serde_bench_no_std::{HeaplessHeader, MAX_PROOF_LENGTH};
+
+#[derive(Serialize, Deserialize)]
+pub struct Header {
+    pub version: u32,
+    pub parent_hash: [u8; 32],
+    pub timestamp: u64,
+    pub number: u64,
+    pub txs_commit: [u8; 32],
+    pub txs_proposal: [u8; 32],
+    pub difficulty: [u8; 32],
+    pub nonce: u64,
+    pub proof: Vec<u8>,
+    pub cellbase_id: [u8; 32],
+    pub uncles_hash: [u8; 32],
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Block {
+    pub header: Header,
+    pub transactions: Vec<Transaction>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Transaction {
+    pub version: u32,
+    pub deps: Vec<OutPoint>,
+    pub inputs: Vec<CellInput>,
+    pub outputs: Vec<CellOutput>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct OutPoint {
+    pub hash: [u8; 32],
+    pub index: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CellInput {
+    pub hash: [u8; 32],
+    pub index: u32,
+    pub unlock: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CellOutput {
+    pub capacity: u64,
+    pub data: Vec<u8>,
+    pub lock: [u8; 32],
+}
@@ -0,0 +1,411 @@
+// Automatically generated rust module for 'bench.proto' file
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+#![allow(unused_imports)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+
+use std::borrow::Cow;
+use quick_protobuf::{MessageInfo, MessageRead, MessageWrite, BytesReader, Writer, WriterBackend, Result};
+use quick_protobuf::sizeofs::*;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct SyncMessage<'a> {
+    pub payload: mod_SyncMessage::OneOfpayload<'a>,
+}
+
+impl<'a> MessageRead<'a> for SyncMessage<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.payload = mod_SyncMessage::OneOfpayload::getheaders(r.read_message::<GetHeaders>(bytes)?),
+                Ok(18) => msg.payload = mod_SyncMessage::OneOfpayload::headers(r.read_message::<Headers>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for SyncMessage<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + match self.payload {
+            mod_SyncMessage::OneOfpayload::getheaders(ref m) => 1 + sizeof_len((m).get_size()),
+            mod_SyncMessage::OneOfpayload::headers(ref m) => 1 + sizeof_len((m).get_size()),
+            mod_SyncMessage::OneOfpayload::None => 0,
+    }    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        match self.payload {            mod_SyncMessage::OneOfpayload::getheaders(ref m) => { w.write_with_tag(10, |w| w.write_message(m))? },
+            mod_SyncMessage::OneOfpayload::headers(ref m) => { w.write_with_tag(18, |w| w.write_message(m))? },
+            mod_SyncMessage::OneOfpayload::None => {},
+    }        Ok(())
+    }
+}
+
+pub mod mod_SyncMessage {
+
+use super::*;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum OneOfpayload<'a> {
+    getheaders(GetHeaders<'a>),
+    headers(Headers<'a>),
+    None,
+}
+
+impl<'a> Default for OneOfpayload<'a> {
+    fn default() -> Self {
+        OneOfpayload::None
+    }
+}
+
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct GetHeaders<'a> {
+    pub version: u32,
+    pub block_locator_hashes: Vec<Cow<'a, [u8]>>,
+    pub hash_stop: Cow<'a, [u8]>,
+}
+
+impl<'a> MessageRead<'a> for GetHeaders<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.version = r.read_uint32(bytes)?,
+                Ok(18) => msg.block_locator_hashes.push(r.read_bytes(bytes).map(Cow::Borrowed)?),
+                Ok(26) => msg.hash_stop = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for GetHeaders<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.version == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.version) as u64) }
+        + self.block_locator_hashes.iter().map(|s| 1 + sizeof_len((s).len())).sum::<usize>()
+        + if self.hash_stop == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.hash_stop).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.version != 0u32 { w.write_with_tag(8, |w| w.write_uint32(*&self.version))?; }
+        for s in &self.block_locator_hashes { w.write_with_tag(18, |w| w.write_bytes(&**s))?; }
+        if self.hash_stop != Cow::Borrowed(b"") { w.write_with_tag(26, |w| w.write_bytes(&**&self.hash_stop))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Headers<'a> {
+    pub headers: Vec<Header<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Headers<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.headers.push(r.read_message::<Header>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Headers<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.headers.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        for s in &self.headers { w.write_with_tag(10, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Header<'a> {
+    pub version: u32,
+    pub parent_hash: Cow<'a, [u8]>,
+    pub timestamp: u64,
+    pub number: u64,
+    pub txs_commit: Cow<'a, [u8]>,
+    pub txs_proposal: Cow<'a, [u8]>,
+    pub difficulty: Cow<'a, [u8]>,
+    pub nonce: u64,
+    pub proof: Cow<'a, [u8]>,
+    pub cellbase_id: Cow<'a, [u8]>,
+    pub uncles_hash: Cow<'a, [u8]>,
+}
+
+impl<'a> MessageRead<'a> for Header<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.version = r.read_uint32(bytes)?,
+                Ok(18) => msg.parent_hash = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(24) => msg.timestamp = r.read_uint64(bytes)?,
+                Ok(32) => msg.number = r.read_uint64(bytes)?,
+                Ok(42) => msg.txs_commit = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(50) => msg.txs_proposal = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(58) => msg.difficulty = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(64) => msg.nonce = r.read_uint64(bytes)?,
+                Ok(74) => msg.proof = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(82) => msg.cellbase_id = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(90) => msg.uncles_hash = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Header<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.version == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.version) as u64) }
+        + if self.parent_hash == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.parent_hash).len()) }
+        + if self.timestamp == 0u64 { 0 } else { 1 + sizeof_varint(*(&self.timestamp) as u64) }
+        + if self.number == 0u64 { 0 } else { 1 + sizeof_varint(*(&self.number) as u64) }
+        + if self.txs_commit == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.txs_commit).len()) }
+        + if self.txs_proposal == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.txs_proposal).len()) }
+        + if self.difficulty == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.difficulty).len()) }
+        + if self.nonce == 0u64 { 0 } else { 1 + sizeof_varint(*(&self.nonce) as u64) }
+        + if self.proof == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.proof).len()) }
+        + if self.cellbase_id == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.cellbase_id).len()) }
+        + if self.uncles_hash == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.uncles_hash).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.version != 0u32 { w.write_with_tag(8, |w| w.write_uint32(*&self.version))?; }
+        if self.parent_hash != Cow::Borrowed(b"") { w.write_with_tag(18, |w| w.write_bytes(&**&self.parent_hash))?; }
+        if self.timestamp != 0u64 { w.write_with_tag(24, |w| w.write_uint64(*&self.timestamp))?; }
+        if self.number != 0u64 { w.write_with_tag(32, |w| w.write_uint64(*&self.number))?; }
+        if self.txs_commit != Cow::Borrowed(b"") { w.write_with_tag(42, |w| w.write_bytes(&**&self.txs_commit))?; }
+        if self.txs_proposal != Cow::Borrowed(b"") { w.write_with_tag(50, |w| w.write_bytes(&**&self.txs_proposal))?; }
+        if self.difficulty != Cow::Borrowed(b"") { w.write_with_tag(58, |w| w.write_bytes(&**&self.difficulty))?; }
+        if self.nonce != 0u64 { w.write_with_tag(64, |w| w.write_uint64(*&self.nonce))?; }
+        if self.proof != Cow::Borrowed(b"") { w.write_with_tag(74, |w| w.write_bytes(&**&self.proof))?; }
+        if self.cellbase_id != Cow::Borrowed(b"") { w.write_with_tag(82, |w| w.write_bytes(&**&self.cellbase_id))?; }
+        if self.uncles_hash != Cow::Borrowed(b"") { w.write_with_tag(90, |w| w.write_bytes(&**&self.uncles_hash))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Block<'a> {
+    pub header: Option<Header<'a>>,
+    pub transactions: Vec<Transaction<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Block<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.header = Some(r.read_message::<Header>(bytes)?),
+                Ok(18) => msg.transactions.push(r.read_message::<Transaction>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Block<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + self.header.as_ref().map_or(0, |m| 1 + sizeof_len((m).get_size()))
+        + self.transactions.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if let Some(ref s) = self.header { w.write_with_tag(10, |w| w.write_message(s))?; }
+        for s in &self.transactions { w.write_with_tag(18, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Transaction<'a> {
+    pub version: u32,
+    pub deps: Vec<OutPoint<'a>>,
+    pub inputs: Vec<CellInput<'a>>,
+    pub outputs: Vec<CellOutput<'a>>,
+}
+
+impl<'a> MessageRead<'a> for Transaction<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.version = r.read_uint32(bytes)?,
+                Ok(18) => msg.deps.push(r.read_message::<OutPoint>(bytes)?),
+                Ok(26) => msg.inputs.push(r.read_message::<CellInput>(bytes)?),
+                Ok(34) => msg.outputs.push(r.read_message::<CellOutput>(bytes)?),
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for Transaction<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.version == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.version) as u64) }
+        + self.deps.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.inputs.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+        + self.outputs.iter().map(|s| 1 + sizeof_len((s).get_size())).sum::<usize>()
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.version != 0u32 { w.write_with_tag(8, |w| w.write_uint32(*&self.version))?; }
+        for s in &self.deps { w.write_with_tag(18, |w| w.write_message(s))?; }
+        for s in &self.inputs { w.write_with_tag(26, |w| w.write_message(s))?; }
+        for s in &self.outputs { w.write_with_tag(34, |w| w.write_message(s))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct OutPoint<'a> {
+    pub hash: Cow<'a, [u8]>,
+    pub index: u32,
+}
+
+impl<'a> MessageRead<'a> for OutPoint<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.hash = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(16) => msg.index = r.read_uint32(bytes)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for OutPoint<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.hash == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.hash).len()) }
+        + if self.index == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.index) as u64) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.hash != Cow::Borrowed(b"") { w.write_with_tag(10, |w| w.write_bytes(&**&self.hash))?; }
+        if self.index != 0u32 { w.write_with_tag(16, |w| w.write_uint32(*&self.index))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CellInput<'a> {
+    pub hash: Cow<'a, [u8]>,
+    pub index: u32,
+    pub unlock: Cow<'a, [u8]>,
+}
+
+impl<'a> MessageRead<'a> for CellInput<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(10) => msg.hash = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(16) => msg.index = r.read_uint32(bytes)?,
+                Ok(26) => msg.unlock = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for CellInput<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.hash == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.hash).len()) }
+        + if self.index == 0u32 { 0 } else { 1 + sizeof_varint(*(&self.index) as u64) }
+        + if self.unlock == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.unlock).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.hash != Cow::Borrowed(b"") { w.write_with_tag(10, |w| w.write_bytes(&**&self.hash))?; }
+        if self.index != 0u32 { w.write_with_tag(16, |w| w.write_uint32(*&self.index))?; }
+        if self.unlock != Cow::Borrowed(b"") { w.write_with_tag(26, |w| w.write_bytes(&**&self.unlock))?; }
+        Ok(())
+    }
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct CellOutput<'a> {
+    pub capacity: u64,
+    pub data: Cow<'a, [u8]>,
+    pub lock: Cow<'a, [u8]>,
+}
+
+impl<'a> MessageRead<'a> for CellOutput<'a> {
+    fn from_reader(r: &mut BytesReader, bytes: &'a [u8]) -> Result<Self> {
+        let mut msg = Self::default();
+        while !r.is_eof() {
+            match r.next_tag(bytes) {
+                Ok(8) => msg.capacity = r.read_uint64(bytes)?,
+                Ok(18) => msg.data = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(26) => msg.lock = r.read_bytes(bytes).map(Cow::Borrowed)?,
+                Ok(t) => { r.read_unknown(bytes, t)?; }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(msg)
+    }
+}
+
+impl<'a> MessageWrite for CellOutput<'a> {
+    fn get_size(&self) -> usize {
+        0
+        + if self.capacity == 0u64 { 0 } else { 1 + sizeof_varint(*(&self.capacity) as u64) }
+        + if self.data == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.data).len()) }
+        + if self.lock == Cow::Borrowed(b"") { 0 } else { 1 + sizeof_len((&self.lock).len()) }
+    }
+
+    fn write_message<W: WriterBackend>(&self, w: &mut Writer<W>) -> Result<()> {
+        if self.capacity != 0u64 { w.write_with_tag(8, |w| w.write_uint64(*&self.capacity))?; }
+        if self.data != Cow::Borrowed(b"") { w.write_with_tag(18, |w| w.write_bytes(&**&self.data))?; }
+        if self.lock != Cow::Borrowed(b"") { w.write_with_tag(26, |w| w.write_bytes(&**&self.lock))?; }
+        Ok(())
+    }
+}
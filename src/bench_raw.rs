@@ -0,0 +1,191 @@
+//! Hand-written fixed layout used as the upper bound in the benches.
+//!
+//! Integers are little-endian at their natural width, hashes are their 32
+//! bytes and every vector has a `u32` little-endian length prefix. There is no
+//! schema, no offsets and no varints: encoding is a series of `memcpy`s and
+//! decoding is a single pass of slice reads. Decoding fails if the input is
+//! truncated or followed by trailing bytes.
+
+use crate::read::{array, take, vec, UnexpectedEof};
+use crate::{Block, CellInput, CellOutput, Header, OutPoint, Seal, Transaction};
+use bigint::H256;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    UnexpectedEof,
+    TrailingBytes,
+}
+
+impl From<UnexpectedEof> for Error {
+    fn from(_: UnexpectedEof) -> Self {
+        Error::UnexpectedEof
+    }
+}
+
+pub trait Raw: Sized {
+    fn write_raw(&self, out: &mut Vec<u8>);
+    fn read_raw(input: &mut &[u8]) -> Result<Self, Error>;
+}
+
+pub fn from_bytes<T: Raw>(mut data: &[u8]) -> Result<T, Error> {
+    let value = T::read_raw(&mut data)?;
+    if data.is_empty() {
+        Ok(value)
+    } else {
+        Err(Error::TrailingBytes)
+    }
+}
+
+impl Raw for Header {
+    fn write_raw(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.version.to_le_bytes());
+        out.extend_from_slice(&self.parent_hash);
+        out.extend_from_slice(&self.timestamp.to_le_bytes());
+        out.extend_from_slice(&self.number.to_le_bytes());
+        out.extend_from_slice(&self.txs_commit);
+        out.extend_from_slice(&self.txs_proposal);
+        out.extend_from_slice(&<[u8; 32]>::from(self.difficulty));
+        out.extend_from_slice(&self.seal.nonce.to_le_bytes());
+        write_bytes(&self.seal.proof, out);
+        out.extend_from_slice(&self.cellbase_id);
+        out.extend_from_slice(&self.uncles_hash);
+    }
+
+    fn read_raw(input: &mut &[u8]) -> Result<Self, Error> {
+        let version = read_u32(input)?;
+        let parent_hash = read_h256(input)?;
+        let timestamp = read_u64(input)?;
+        let number = read_u64(input)?;
+        let txs_commit = read_h256(input)?;
+        let txs_proposal = read_h256(input)?;
+        let difficulty = read_h256(input)?.into();
+        let nonce = read_u64(input)?;
+        let proof = read_bytes(input)?;
+        let cellbase_id = read_h256(input)?;
+        let uncles_hash = read_h256(input)?;
+
+        Ok(Header {
+            version,
+            parent_hash,
+            timestamp,
+            number,
+            txs_commit,
+            txs_proposal,
+            difficulty,
+            cellbase_id,
+            uncles_hash,
+            seal: Seal { nonce, proof },
+        })
+    }
+}
+
+impl Raw for Block {
+    fn write_raw(&self, out: &mut Vec<u8>) {
+        self.header.write_raw(out);
+        write_vec(&self.transactions, out);
+    }
+
+    fn read_raw(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(Block {
+            header: Header::read_raw(input)?,
+            transactions: read_vec(input)?,
+        })
+    }
+}
+
+impl Raw for Transaction {
+    fn write_raw(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.version.to_le_bytes());
+        write_vec(&self.deps, out);
+        write_vec(&self.inputs, out);
+        write_vec(&self.outputs, out);
+    }
+
+    fn read_raw(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(Transaction {
+            version: read_u32(input)?,
+            deps: read_vec(input)?,
+            inputs: read_vec(input)?,
+            outputs: read_vec(input)?,
+        })
+    }
+}
+
+impl Raw for OutPoint {
+    fn write_raw(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.hash);
+        out.extend_from_slice(&self.index.to_le_bytes());
+    }
+
+    fn read_raw(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(OutPoint {
+            hash: read_h256(input)?,
+            index: read_u32(input)?,
+        })
+    }
+}
+
+impl Raw for CellInput {
+    fn write_raw(&self, out: &mut Vec<u8>) {
+        self.previous_output.write_raw(out);
+        write_bytes(&self.unlock, out);
+    }
+
+    fn read_raw(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(CellInput {
+            previous_output: OutPoint::read_raw(input)?,
+            unlock: read_bytes(input)?,
+        })
+    }
+}
+
+impl Raw for CellOutput {
+    fn write_raw(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.capacity.to_le_bytes());
+        write_bytes(&self.data, out);
+        out.extend_from_slice(&self.lock);
+    }
+
+    fn read_raw(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(CellOutput {
+            capacity: read_u64(input)?,
+            data: read_bytes(input)?,
+            lock: read_h256(input)?,
+        })
+    }
+}
+
+fn write_bytes(bytes: &[u8], out: &mut Vec<u8>) {
+    out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    out.extend_from_slice(bytes);
+}
+
+fn write_vec<T: Raw>(items: &[T], out: &mut Vec<u8>) {
+    out.extend_from_slice(&(items.len() as u32).to_le_bytes());
+    for item in items {
+        item.write_raw(out);
+    }
+}
+
+fn read_u32(input: &mut &[u8]) -> Result<u32, Error> {
+    Ok(u32::from_le_bytes(array(input)?))
+}
+
+fn read_u64(input: &mut &[u8]) -> Result<u64, Error> {
+    Ok(u64::from_le_bytes(array(input)?))
+}
+
+fn read_h256(input: &mut &[u8]) -> Result<H256, Error> {
+    Ok(H256::from(array::<32>(input)?))
+}
+
+fn read_bytes(input: &mut &[u8]) -> Result<Vec<u8>, Error> {
+    let len = read_u32(input)? as usize;
+    Ok(take(input, len)?.to_vec())
+}
+
+fn read_vec<T: Raw>(input: &mut &[u8]) -> Result<Vec<T>, Error> {
+    let len = read_u32(input)? as usize;
+    // Every item takes at least 4 bytes.
+    vec(input, len, 4, T::read_raw)
+}
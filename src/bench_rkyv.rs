@@ -0,0 +1,62 @@
+//! rkyv wrappers for the `bigint` field types of the domain model.
+//!
+//! `H256` and `U256` are archived as plain `[u8; 32]` arrays, so the archived
+//! structs need no extra relative pointers for hashes.
+
+use bigint::{H256, U256};
+use rkyv::with::{ArchiveWith, DeserializeWith, SerializeWith};
+use rkyv::{Archive, Fallible};
+
+pub struct AsByte32;
+
+impl ArchiveWith<H256> for AsByte32 {
+    type Archived = [u8; 32];
+    type Resolver = [(); 32];
+
+    unsafe fn resolve_with(
+        field: &H256,
+        pos: usize,
+        resolver: Self::Resolver,
+        out: *mut Self::Archived,
+    ) {
+        <[u8; 32]>::from(*field).resolve(pos, resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> SerializeWith<H256, S> for AsByte32 {
+    fn serialize_with(_field: &H256, _serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok([(); 32])
+    }
+}
+
+impl<D: Fallible + ?Sized> DeserializeWith<[u8; 32], H256, D> for AsByte32 {
+    fn deserialize_with(field: &[u8; 32], _deserializer: &mut D) -> Result<H256, D::Error> {
+        Ok(H256::from(*field))
+    }
+}
+
+impl ArchiveWith<U256> for AsByte32 {
+    type Archived = [u8; 32];
+    type Resolver = [(); 32];
+
+    unsafe fn resolve_with(
+        field: &U256,
+        pos: usize,
+        resolver: Self::Resolver,
+        out: *mut Self::Archived,
+    ) {
+        <[u8; 32]>::from(*field).resolve(pos, resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> SerializeWith<U256, S> for AsByte32 {
+    fn serialize_with(_field: &U256, _serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok([(); 32])
+    }
+}
+
+impl<D: Fallible + ?Sized> DeserializeWith<[u8; 32], U256, D> for AsByte32 {
+    fn deserialize_with(field: &[u8; 32], _deserializer: &mut D) -> Result<U256, D::Error> {
+        Ok(H256::from(*field).into())
+    }
+}
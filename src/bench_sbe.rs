@@ -0,0 +1,334 @@
+//! Simple Binary Encoding codec for `schemas/bench.sbe.xml`.
+//!
+//! Every message starts with the 8-byte SBE message header, followed by a
+//! root block of fixed-offset fields, then repeating groups and finally
+//! variable-length data. Integers are little-endian. A group is prefixed with
+//! its entry block length and entry count, and each entry repeats the same
+//! layout: fixed fields, nested groups, then its own variable-length data.
+//!
+//! Decoding follows the SBE rules for schema extension: a root block or group
+//! entry longer than the one this schema knows about is accepted and the
+//! unknown trailing fields are skipped.
+//!
+//! The schema limits a group to `u16::MAX` entries (`numInGroup` is a
+//! `uint16`) and variable-length data to `MAX_VAR_DATA_LENGTH` bytes.
+//! Encoding fails with `GroupTooLong` or `VarDataTooLong` above them, and
+//! decoding rejects a var data length above its `maxValue`.
+
+use crate::read::{array, take, vec, UnexpectedEof};
+use std::convert::{TryFrom, TryInto};
+
+pub const SCHEMA_ID: u16 = 1;
+pub const SCHEMA_VERSION: u16 = 0;
+pub const MESSAGE_HEADER_LENGTH: usize = 8;
+
+/// Length of the root block shared by `Header` and `Block`.
+pub const ROOT_BLOCK_LENGTH: u16 = 220;
+const TRANSACTION_BLOCK_LENGTH: u16 = 4;
+const OUT_POINT_BLOCK_LENGTH: u16 = 36;
+const CELL_INPUT_BLOCK_LENGTH: u16 = 36;
+const CELL_OUTPUT_BLOCK_LENGTH: u16 = 40;
+
+/// `maxValue` of the `varDataEncoding` length.
+pub const MAX_VAR_DATA_LENGTH: usize = 1 << 30;
+
+pub struct Header {
+    pub version: u32,
+    pub parent_hash: [u8; 32],
+    pub timestamp: u64,
+    pub number: u64,
+    pub txs_commit: [u8; 32],
+    pub txs_proposal: [u8; 32],
+    pub difficulty: [u8; 32],
+    pub nonce: u64,
+    pub proof: Vec<u8>,
+    pub cellbase_id: [u8; 32],
+    pub uncles_hash: [u8; 32],
+}
+
+pub struct Block {
+    pub header: Header,
+    pub transactions: Vec<Transaction>,
+}
+
+pub struct Transaction {
+    pub version: u32,
+    pub deps: Vec<OutPoint>,
+    pub inputs: Vec<CellInput>,
+    pub outputs: Vec<CellOutput>,
+}
+
+pub struct OutPoint {
+    pub hash: [u8; 32],
+    pub index: u32,
+}
+
+pub struct CellInput {
+    pub hash: [u8; 32],
+    pub index: u32,
+    pub unlock: Vec<u8>,
+}
+
+pub struct CellOutput {
+    pub capacity: u64,
+    pub data: Vec<u8>,
+    pub lock: [u8; 32],
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    UnexpectedEof,
+    UnknownSchema(u16),
+    UnknownTemplate(u16),
+    BlockLengthTooShort,
+    TrailingBytes,
+    GroupTooLong(usize),
+    VarDataTooLong(usize),
+}
+
+impl From<UnexpectedEof> for Error {
+    fn from(_: UnexpectedEof) -> Self {
+        Error::UnexpectedEof
+    }
+}
+
+/// A top-level message, identified by its template id in the message header.
+pub trait Message: Sized {
+    const TEMPLATE_ID: u16;
+
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), Error>;
+    fn decode(root: &[u8], input: &mut &[u8]) -> Result<Self, Error>;
+}
+
+/// An entry of a repeating group.
+pub trait Entry: Sized {
+    const BLOCK_LENGTH: u16;
+
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), Error>;
+    fn decode(block: &[u8], input: &mut &[u8]) -> Result<Self, Error>;
+}
+
+pub fn to_bytes<T: Message>(message: &T) -> Result<Vec<u8>, Error> {
+    let mut out = Vec::new();
+    out.extend_from_slice(&ROOT_BLOCK_LENGTH.to_le_bytes());
+    out.extend_from_slice(&T::TEMPLATE_ID.to_le_bytes());
+    out.extend_from_slice(&SCHEMA_ID.to_le_bytes());
+    out.extend_from_slice(&SCHEMA_VERSION.to_le_bytes());
+    message.encode(&mut out)?;
+    Ok(out)
+}
+
+pub fn from_bytes<T: Message>(mut data: &[u8]) -> Result<T, Error> {
+    let input = &mut data;
+    let block_length = u16::from_le_bytes(array(input)?);
+    let template_id = u16::from_le_bytes(array(input)?);
+    let schema_id = u16::from_le_bytes(array(input)?);
+    let _version = u16::from_le_bytes(array(input)?);
+    if schema_id != SCHEMA_ID {
+        return Err(Error::UnknownSchema(schema_id));
+    }
+    if template_id != T::TEMPLATE_ID {
+        return Err(Error::UnknownTemplate(template_id));
+    }
+    if block_length < ROOT_BLOCK_LENGTH {
+        return Err(Error::BlockLengthTooShort);
+    }
+    let root = take(input, block_length as usize)?;
+    let message = T::decode(root, input)?;
+    if input.is_empty() {
+        Ok(message)
+    } else {
+        Err(Error::TrailingBytes)
+    }
+}
+
+impl Message for Header {
+    const TEMPLATE_ID: u16 = 1;
+
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        encode_root(self, out);
+        encode_var_data(&self.proof, out)
+    }
+
+    fn decode(root: &[u8], input: &mut &[u8]) -> Result<Self, Error> {
+        let mut header = decode_root(root);
+        header.proof = decode_var_data(input)?;
+        Ok(header)
+    }
+}
+
+impl Message for Block {
+    const TEMPLATE_ID: u16 = 2;
+
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        encode_root(&self.header, out);
+        encode_group(&self.transactions, out)?;
+        encode_var_data(&self.header.proof, out)
+    }
+
+    fn decode(root: &[u8], input: &mut &[u8]) -> Result<Self, Error> {
+        let mut header = decode_root(root);
+        let transactions = decode_group(input)?;
+        header.proof = decode_var_data(input)?;
+        Ok(Block {
+            header,
+            transactions,
+        })
+    }
+}
+
+impl Entry for Transaction {
+    const BLOCK_LENGTH: u16 = TRANSACTION_BLOCK_LENGTH;
+
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        out.extend_from_slice(&self.version.to_le_bytes());
+        encode_group(&self.deps, out)?;
+        encode_group(&self.inputs, out)?;
+        encode_group(&self.outputs, out)
+    }
+
+    fn decode(block: &[u8], input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(Transaction {
+            version: get_u32(block, 0),
+            deps: decode_group(input)?,
+            inputs: decode_group(input)?,
+            outputs: decode_group(input)?,
+        })
+    }
+}
+
+impl Entry for OutPoint {
+    const BLOCK_LENGTH: u16 = OUT_POINT_BLOCK_LENGTH;
+
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        out.extend_from_slice(&self.hash);
+        out.extend_from_slice(&self.index.to_le_bytes());
+        Ok(())
+    }
+
+    fn decode(block: &[u8], _input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(OutPoint {
+            hash: get_byte32(block, 0),
+            index: get_u32(block, 32),
+        })
+    }
+}
+
+impl Entry for CellInput {
+    const BLOCK_LENGTH: u16 = CELL_INPUT_BLOCK_LENGTH;
+
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        out.extend_from_slice(&self.hash);
+        out.extend_from_slice(&self.index.to_le_bytes());
+        encode_var_data(&self.unlock, out)
+    }
+
+    fn decode(block: &[u8], input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(CellInput {
+            hash: get_byte32(block, 0),
+            index: get_u32(block, 32),
+            unlock: decode_var_data(input)?,
+        })
+    }
+}
+
+impl Entry for CellOutput {
+    const BLOCK_LENGTH: u16 = CELL_OUTPUT_BLOCK_LENGTH;
+
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), Error> {
+        out.extend_from_slice(&self.capacity.to_le_bytes());
+        out.extend_from_slice(&self.lock);
+        encode_var_data(&self.data, out)
+    }
+
+    fn decode(block: &[u8], input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(CellOutput {
+            capacity: get_u64(block, 0),
+            lock: get_byte32(block, 8),
+            data: decode_var_data(input)?,
+        })
+    }
+}
+
+fn encode_root(header: &Header, out: &mut Vec<u8>) {
+    out.extend_from_slice(&header.version.to_le_bytes());
+    out.extend_from_slice(&header.parent_hash);
+    out.extend_from_slice(&header.timestamp.to_le_bytes());
+    out.extend_from_slice(&header.number.to_le_bytes());
+    out.extend_from_slice(&header.txs_commit);
+    out.extend_from_slice(&header.txs_proposal);
+    out.extend_from_slice(&header.difficulty);
+    out.extend_from_slice(&header.nonce.to_le_bytes());
+    out.extend_from_slice(&header.cellbase_id);
+    out.extend_from_slice(&header.uncles_hash);
+}
+
+/// Reads the header fields from the root block, `proof` is left empty since it
+/// comes after the groups.
+fn decode_root(root: &[u8]) -> Header {
+    Header {
+        version: get_u32(root, 0),
+        parent_hash: get_byte32(root, 4),
+        timestamp: get_u64(root, 36),
+        number: get_u64(root, 44),
+        txs_commit: get_byte32(root, 52),
+        txs_proposal: get_byte32(root, 84),
+        difficulty: get_byte32(root, 116),
+        nonce: get_u64(root, 148),
+        proof: Vec::new(),
+        cellbase_id: get_byte32(root, 156),
+        uncles_hash: get_byte32(root, 188),
+    }
+}
+
+fn encode_group<T: Entry>(entries: &[T], out: &mut Vec<u8>) -> Result<(), Error> {
+    let count = u16::try_from(entries.len()).map_err(|_| Error::GroupTooLong(entries.len()))?;
+    out.extend_from_slice(&T::BLOCK_LENGTH.to_le_bytes());
+    out.extend_from_slice(&count.to_le_bytes());
+    entries.iter().try_for_each(|entry| entry.encode(out))
+}
+
+fn decode_group<T: Entry>(input: &mut &[u8]) -> Result<Vec<T>, Error> {
+    let block_length = u16::from_le_bytes(array(input)?);
+    let count = u16::from_le_bytes(array(input)?) as usize;
+    if block_length < T::BLOCK_LENGTH {
+        return Err(Error::BlockLengthTooShort);
+    }
+    // Every entry takes at least its block.
+    vec(input, count, block_length as usize, |input| {
+        let block = take(input, block_length as usize)?;
+        T::decode(block, input)
+    })
+}
+
+fn encode_var_data(data: &[u8], out: &mut Vec<u8>) -> Result<(), Error> {
+    if data.len() > MAX_VAR_DATA_LENGTH {
+        return Err(Error::VarDataTooLong(data.len()));
+    }
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out.extend_from_slice(data);
+    Ok(())
+}
+
+fn decode_var_data(input: &mut &[u8]) -> Result<Vec<u8>, Error> {
+    let len = u32::from_le_bytes(array(input)?) as usize;
+    if len > MAX_VAR_DATA_LENGTH {
+        return Err(Error::VarDataTooLong(len));
+    }
+    Ok(take(input, len)?.to_vec())
+}
+
+// The block lengths were checked against the schema before an entry is
+// decoded, so fixed-offset reads are always in bounds.
+
+fn get_u32(block: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(block[offset..offset + 4].try_into().unwrap())
+}
+
+fn get_u64(block: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(block[offset..offset + 8].try_into().unwrap())
+}
+
+fn get_byte32(block: &[u8], offset: usize) -> [u8; 32] {
+    block[offset..offset + 32].try_into().unwrap()
+}
@@ -0,0 +1,63 @@
+//! SCALE wire types mirroring `schemas/bench.mol`.
+//!
+//! Integers are compact-encoded, hashes are fixed `[u8; 32]` arrays and byte
+//! vectors carry a compact length prefix.
+
+use parity_scale_codec::{Decode, Encode};
+
+#[derive(Encode, Decode)]
+pub struct Header {
+    #[codec(compact)]
+    pub version: u32,
+    pub parent_hash: [u8; 32],
+    #[codec(compact)]
+    pub timestamp: u64,
+    #[codec(compact)]
+    pub number: u64,
+    pub txs_commit: [u8; 32],
+    pub txs_proposal: [u8; 32],
+    pub difficulty: [u8; 32],
+    #[codec(compact)]
+    pub nonce: u64,
+    pub proof: Vec<u8>,
+    pub cellbase_id: [u8; 32],
+    pub uncles_hash: [u8; 32],
+}
+
+#[derive(Encode, Decode)]
+pub struct Block {
+    pub header: Header,
+    pub transactions: Vec<Transaction>,
+}
+
+#[derive(Encode, Decode)]
+pub struct Transaction {
+    #[codec(compact)]
+    pub version: u32,
+    pub deps: Vec<OutPoint>,
+    pub inputs: Vec<CellInput>,
+    pub outputs: Vec<CellOutput>,
+}
+
+#[derive(Encode, Decode)]
+pub struct OutPoint {
+    pub hash: [u8; 32],
+    #[codec(compact)]
+    pub index: u32,
+}
+
+#[derive(Encode, Decode)]
+pub struct CellInput {
+    pub hash: [u8; 32],
+    #[codec(compact)]
+    pub index: u32,
+    pub unlock: Vec<u8>,
+}
+
+#[derive(Encode, Decode)]
+pub struct CellOutput {
+    #[codec(compact)]
+    pub capacity: u64,
+    pub data: Vec<u8>,
+    pub lock: [u8; 32],
+}
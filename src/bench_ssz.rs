@@ -0,0 +1,291 @@
+//! SSZ wire types mirroring `schemas/bench.mol`.
+//!
+//! Fixed-size fields are laid out inline and every variable-length field is
+//! replaced by a 4-byte offset into the trailing heap, just like a Molecule
+//! table. `HashTreeRoot` implements SSZ Merkleization over the same types.
+//!
+//! The list limits below are part of the type: a list longer than its limit
+//! has no SSZ encoding and no root. `CheckLimits` rejects such values, the
+//! lib checks it after decoding and before encoding.
+
+use sha2::{Digest, Sha256};
+use ssz_derive::{Decode, Encode};
+use std::sync::OnceLock;
+
+/// Maximum length of `proof`, `unlock` and `data`.
+pub const MAX_BYTES_LENGTH: usize = 1 << 20;
+/// Maximum length of `deps`, `inputs` and `outputs`.
+pub const MAX_IO_LENGTH: usize = 1 << 10;
+/// Maximum length of `transactions`.
+pub const MAX_TRANSACTIONS_LENGTH: usize = 1 << 16;
+
+const BYTES_PER_CHUNK: usize = 32;
+const MAX_TREE_DEPTH: usize = 32;
+
+#[derive(Encode, Decode)]
+pub struct Header {
+    pub version: u32,
+    pub parent_hash: [u8; 32],
+    pub timestamp: u64,
+    pub number: u64,
+    pub txs_commit: [u8; 32],
+    pub txs_proposal: [u8; 32],
+    pub difficulty: [u8; 32],
+    pub nonce: u64,
+    pub proof: Vec<u8>,
+    pub cellbase_id: [u8; 32],
+    pub uncles_hash: [u8; 32],
+}
+
+#[derive(Encode, Decode)]
+pub struct Block {
+    pub header: Header,
+    pub transactions: Vec<Transaction>,
+}
+
+#[derive(Encode, Decode)]
+pub struct Transaction {
+    pub version: u32,
+    pub deps: Vec<OutPoint>,
+    pub inputs: Vec<CellInput>,
+    pub outputs: Vec<CellOutput>,
+}
+
+#[derive(Encode, Decode)]
+pub struct OutPoint {
+    pub hash: [u8; 32],
+    pub index: u32,
+}
+
+#[derive(Encode, Decode)]
+pub struct CellInput {
+    pub hash: [u8; 32],
+    pub index: u32,
+    pub unlock: Vec<u8>,
+}
+
+#[derive(Encode, Decode)]
+pub struct CellOutput {
+    pub capacity: u64,
+    pub data: Vec<u8>,
+    pub lock: [u8; 32],
+}
+
+/// A list that is longer than the limit of its SSZ type.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LengthError {
+    pub length: usize,
+    pub limit: usize,
+}
+
+pub trait CheckLimits {
+    fn check_limits(&self) -> Result<(), LengthError>;
+}
+
+impl CheckLimits for Header {
+    fn check_limits(&self) -> Result<(), LengthError> {
+        check_length(self.proof.len(), MAX_BYTES_LENGTH)
+    }
+}
+
+impl CheckLimits for Block {
+    fn check_limits(&self) -> Result<(), LengthError> {
+        self.header.check_limits()?;
+        check_list(&self.transactions, MAX_TRANSACTIONS_LENGTH)
+    }
+}
+
+impl CheckLimits for Transaction {
+    fn check_limits(&self) -> Result<(), LengthError> {
+        check_list(&self.deps, MAX_IO_LENGTH)?;
+        check_list(&self.inputs, MAX_IO_LENGTH)?;
+        check_list(&self.outputs, MAX_IO_LENGTH)
+    }
+}
+
+impl CheckLimits for OutPoint {
+    fn check_limits(&self) -> Result<(), LengthError> {
+        Ok(())
+    }
+}
+
+impl CheckLimits for CellInput {
+    fn check_limits(&self) -> Result<(), LengthError> {
+        check_length(self.unlock.len(), MAX_BYTES_LENGTH)
+    }
+}
+
+impl CheckLimits for CellOutput {
+    fn check_limits(&self) -> Result<(), LengthError> {
+        check_length(self.data.len(), MAX_BYTES_LENGTH)
+    }
+}
+
+fn check_length(length: usize, limit: usize) -> Result<(), LengthError> {
+    if length <= limit {
+        Ok(())
+    } else {
+        Err(LengthError { length, limit })
+    }
+}
+
+fn check_list<T: CheckLimits>(items: &[T], limit: usize) -> Result<(), LengthError> {
+    check_length(items.len(), limit)?;
+    items.iter().try_for_each(CheckLimits::check_limits)
+}
+
+pub trait HashTreeRoot {
+    fn hash_tree_root(&self) -> [u8; 32];
+}
+
+impl HashTreeRoot for Header {
+    fn hash_tree_root(&self) -> [u8; 32] {
+        merkleize(
+            &[
+                uint_root(self.version.into()),
+                self.parent_hash,
+                uint_root(self.timestamp),
+                uint_root(self.number),
+                self.txs_commit,
+                self.txs_proposal,
+                self.difficulty,
+                uint_root(self.nonce),
+                bytes_root(&self.proof),
+                self.cellbase_id,
+                self.uncles_hash,
+            ],
+            11,
+        )
+    }
+}
+
+impl HashTreeRoot for Block {
+    fn hash_tree_root(&self) -> [u8; 32] {
+        merkleize(
+            &[
+                self.header.hash_tree_root(),
+                list_root(&self.transactions, MAX_TRANSACTIONS_LENGTH),
+            ],
+            2,
+        )
+    }
+}
+
+impl HashTreeRoot for Transaction {
+    fn hash_tree_root(&self) -> [u8; 32] {
+        merkleize(
+            &[
+                uint_root(self.version.into()),
+                list_root(&self.deps, MAX_IO_LENGTH),
+                list_root(&self.inputs, MAX_IO_LENGTH),
+                list_root(&self.outputs, MAX_IO_LENGTH),
+            ],
+            4,
+        )
+    }
+}
+
+impl HashTreeRoot for OutPoint {
+    fn hash_tree_root(&self) -> [u8; 32] {
+        merkleize(&[self.hash, uint_root(self.index.into())], 2)
+    }
+}
+
+impl HashTreeRoot for CellInput {
+    fn hash_tree_root(&self) -> [u8; 32] {
+        merkleize(
+            &[
+                self.hash,
+                uint_root(self.index.into()),
+                bytes_root(&self.unlock),
+            ],
+            3,
+        )
+    }
+}
+
+impl HashTreeRoot for CellOutput {
+    fn hash_tree_root(&self) -> [u8; 32] {
+        merkleize(
+            &[uint_root(self.capacity), bytes_root(&self.data), self.lock],
+            3,
+        )
+    }
+}
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+fn mix_in_length(root: &[u8; 32], length: usize) -> [u8; 32] {
+    hash_pair(root, &uint_root(length as u64))
+}
+
+fn uint_root(value: u64) -> [u8; 32] {
+    let mut chunk = [0u8; 32];
+    chunk[..8].copy_from_slice(&value.to_le_bytes());
+    chunk
+}
+
+/// Root of a `List[byte, MAX_BYTES_LENGTH]`.
+fn bytes_root(bytes: &[u8]) -> [u8; 32] {
+    let chunks: Vec<[u8; 32]> = bytes
+        .chunks(BYTES_PER_CHUNK)
+        .map(|slice| {
+            let mut chunk = [0u8; 32];
+            chunk[..slice.len()].copy_from_slice(slice);
+            chunk
+        })
+        .collect();
+    let limit = MAX_BYTES_LENGTH.div_ceil(BYTES_PER_CHUNK);
+    mix_in_length(&merkleize(&chunks, limit), bytes.len())
+}
+
+/// Root of a `List[T, limit]` of composite elements.
+fn list_root<T: HashTreeRoot>(items: &[T], limit: usize) -> [u8; 32] {
+    let chunks: Vec<[u8; 32]> = items.iter().map(HashTreeRoot::hash_tree_root).collect();
+    mix_in_length(&merkleize(&chunks, limit), items.len())
+}
+
+/// Roots of all-zero subtrees, indexed by depth.
+fn zero_hashes() -> &'static [[u8; 32]] {
+    static ZERO_HASHES: OnceLock<Vec<[u8; 32]>> = OnceLock::new();
+    ZERO_HASHES.get_or_init(|| {
+        let mut hashes = vec![[0u8; 32]; MAX_TREE_DEPTH + 1];
+        for i in 0..MAX_TREE_DEPTH {
+            hashes[i + 1] = hash_pair(&hashes[i], &hashes[i]);
+        }
+        hashes
+    })
+}
+
+/// Merkleizes `chunks` as the leaves of a tree padded with zero chunks up to
+/// `limit.next_power_of_two()` leaves. Padding subtrees are not hashed, their
+/// roots are taken from `zero_hashes` instead.
+///
+/// Panics if there are more chunks than `limit`, i.e. a list is over its limit.
+fn merkleize(chunks: &[[u8; 32]], limit: usize) -> [u8; 32] {
+    assert!(
+        chunks.len() <= limit,
+        "{} chunks over the limit of {}",
+        chunks.len(),
+        limit
+    );
+    let depth = limit.next_power_of_two().trailing_zeros() as usize;
+    let zero_hashes = zero_hashes();
+
+    let mut layer = chunks.to_vec();
+    for zero_hash in zero_hashes.iter().take(depth) {
+        if layer.len() % 2 == 1 {
+            layer.push(*zero_hash);
+        }
+        layer = layer
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], &pair[1]))
+            .collect();
+    }
+    layer.first().copied().unwrap_or(zero_hashes[depth])
+}
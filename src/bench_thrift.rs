@@ -0,0 +1,928 @@
+// Autogenerated by Thrift Compiler (0.17.0)
+// DO NOT EDIT UNLESS YOU ARE SURE THAT YOU KNOW WHAT YOU ARE DOING
+
+#![allow(unused_imports)]
+#![allow(unused_extern_crates)]
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::{From, TryFrom};
+use std::default::Default;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+use thrift::OrderedFloat;
+use thrift::{ApplicationError, ApplicationErrorKind, ProtocolError, ProtocolErrorKind, TThriftClient};
+use thrift::protocol::{TFieldIdentifier, TListIdentifier, TMapIdentifier, TMessageIdentifier, TMessageType, TInputProtocol, TOutputProtocol, TSerializable, TSetIdentifier, TStructIdentifier, TType};
+use thrift::protocol::field_id;
+use thrift::protocol::verify_expected_message_type;
+use thrift::protocol::verify_expected_sequence_number;
+use thrift::protocol::verify_expected_service_call;
+use thrift::protocol::verify_required_field_exists;
+
+//
+// SyncPayload
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SyncPayload {
+  GetHeaders(GetHeaders),
+  Headers(Headers),
+}
+
+impl TSerializable for SyncPayload {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<SyncPayload> {
+    let mut ret: Option<SyncPayload> = None;
+    let mut received_field_count = 0;
+    i_prot.read_struct_begin()?;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = GetHeaders::read_from_in_protocol(i_prot)?;
+          if ret.is_none() {
+            ret = Some(SyncPayload::GetHeaders(val));
+          }
+          received_field_count += 1;
+        },
+        2 => {
+          let val = Headers::read_from_in_protocol(i_prot)?;
+          if ret.is_none() {
+            ret = Some(SyncPayload::Headers(val));
+          }
+          received_field_count += 1;
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+          received_field_count += 1;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    if received_field_count == 0 {
+      Err(
+        thrift::Error::Protocol(
+          ProtocolError::new(
+            ProtocolErrorKind::InvalidData,
+            "received empty union from remote SyncPayload"
+          )
+        )
+      )
+    } else if received_field_count > 1 {
+      Err(
+        thrift::Error::Protocol(
+          ProtocolError::new(
+            ProtocolErrorKind::InvalidData,
+            "received multiple fields for union from remote SyncPayload"
+          )
+        )
+      )
+    } else {
+      Ok(ret.expect("return value should have been constructed"))
+    }
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("SyncPayload");
+    o_prot.write_struct_begin(&struct_ident)?;
+    match *self {
+      SyncPayload::GetHeaders(ref f) => {
+        o_prot.write_field_begin(&TFieldIdentifier::new("get_headers", TType::Struct, 1))?;
+        f.write_to_out_protocol(o_prot)?;
+        o_prot.write_field_end()?;
+      },
+      SyncPayload::Headers(ref f) => {
+        o_prot.write_field_begin(&TFieldIdentifier::new("headers", TType::Struct, 2))?;
+        f.write_to_out_protocol(o_prot)?;
+        o_prot.write_field_end()?;
+      },
+    }
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// SyncMessage
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SyncMessage {
+  pub payload: SyncPayload,
+}
+
+impl SyncMessage {
+  pub fn new(payload: SyncPayload) -> SyncMessage {
+    SyncMessage {
+      payload,
+    }
+  }
+}
+
+impl TSerializable for SyncMessage {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<SyncMessage> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<SyncPayload> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = SyncPayload::read_from_in_protocol(i_prot)?;
+          f_1 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("SyncMessage.payload", &f_1)?;
+    let ret = SyncMessage {
+      payload: f_1.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("SyncMessage");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("payload", TType::Struct, 1))?;
+    self.payload.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// GetHeaders
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct GetHeaders {
+  pub version: i32,
+  pub block_locator_hashes: Vec<Vec<u8>>,
+  pub hash_stop: Vec<u8>,
+}
+
+impl GetHeaders {
+  pub fn new(version: i32, block_locator_hashes: Vec<Vec<u8>>, hash_stop: Vec<u8>) -> GetHeaders {
+    GetHeaders {
+      version,
+      block_locator_hashes,
+      hash_stop,
+    }
+  }
+}
+
+impl TSerializable for GetHeaders {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<GetHeaders> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<i32> = None;
+    let mut f_2: Option<Vec<Vec<u8>>> = None;
+    let mut f_3: Option<Vec<u8>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_i32()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<Vec<u8>> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_0 = i_prot.read_bytes()?;
+            val.push(list_elem_0);
+          }
+          i_prot.read_list_end()?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = i_prot.read_bytes()?;
+          f_3 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("GetHeaders.version", &f_1)?;
+    verify_required_field_exists("GetHeaders.block_locator_hashes", &f_2)?;
+    verify_required_field_exists("GetHeaders.hash_stop", &f_3)?;
+    let ret = GetHeaders {
+      version: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      block_locator_hashes: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      hash_stop: f_3.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("GetHeaders");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("version", TType::I32, 1))?;
+    o_prot.write_i32(self.version)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("block_locator_hashes", TType::List, 2))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::String, self.block_locator_hashes.len() as i32))?;
+    for e in &self.block_locator_hashes {
+      o_prot.write_bytes(e)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("hash_stop", TType::String, 3))?;
+    o_prot.write_bytes(&self.hash_stop)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Headers
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Headers {
+  pub headers: Vec<Header>,
+}
+
+impl Headers {
+  pub fn new(headers: Vec<Header>) -> Headers {
+    Headers {
+      headers,
+    }
+  }
+}
+
+impl TSerializable for Headers {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Headers> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Vec<Header>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<Header> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_1 = Header::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_1);
+          }
+          i_prot.read_list_end()?;
+          f_1 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Headers.headers", &f_1)?;
+    let ret = Headers {
+      headers: f_1.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Headers");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("headers", TType::List, 1))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.headers.len() as i32))?;
+    for e in &self.headers {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Header
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Header {
+  pub version: i32,
+  pub parent_hash: Vec<u8>,
+  pub timestamp: i64,
+  pub number: i64,
+  pub txs_commit: Vec<u8>,
+  pub txs_proposal: Vec<u8>,
+  pub difficulty: Vec<u8>,
+  pub nonce: i64,
+  pub proof: Vec<u8>,
+  pub cellbase_id: Vec<u8>,
+  pub uncles_hash: Vec<u8>,
+}
+
+impl Header {
+  pub fn new(version: i32, parent_hash: Vec<u8>, timestamp: i64, number: i64, txs_commit: Vec<u8>, txs_proposal: Vec<u8>, difficulty: Vec<u8>, nonce: i64, proof: Vec<u8>, cellbase_id: Vec<u8>, uncles_hash: Vec<u8>) -> Header {
+    Header {
+      version,
+      parent_hash,
+      timestamp,
+      number,
+      txs_commit,
+      txs_proposal,
+      difficulty,
+      nonce,
+      proof,
+      cellbase_id,
+      uncles_hash,
+    }
+  }
+}
+
+impl TSerializable for Header {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Header> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<i32> = None;
+    let mut f_2: Option<Vec<u8>> = None;
+    let mut f_3: Option<i64> = None;
+    let mut f_4: Option<i64> = None;
+    let mut f_5: Option<Vec<u8>> = None;
+    let mut f_6: Option<Vec<u8>> = None;
+    let mut f_7: Option<Vec<u8>> = None;
+    let mut f_8: Option<i64> = None;
+    let mut f_9: Option<Vec<u8>> = None;
+    let mut f_10: Option<Vec<u8>> = None;
+    let mut f_11: Option<Vec<u8>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_i32()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = i_prot.read_bytes()?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = i_prot.read_i64()?;
+          f_3 = Some(val);
+        },
+        4 => {
+          let val = i_prot.read_i64()?;
+          f_4 = Some(val);
+        },
+        5 => {
+          let val = i_prot.read_bytes()?;
+          f_5 = Some(val);
+        },
+        6 => {
+          let val = i_prot.read_bytes()?;
+          f_6 = Some(val);
+        },
+        7 => {
+          let val = i_prot.read_bytes()?;
+          f_7 = Some(val);
+        },
+        8 => {
+          let val = i_prot.read_i64()?;
+          f_8 = Some(val);
+        },
+        9 => {
+          let val = i_prot.read_bytes()?;
+          f_9 = Some(val);
+        },
+        10 => {
+          let val = i_prot.read_bytes()?;
+          f_10 = Some(val);
+        },
+        11 => {
+          let val = i_prot.read_bytes()?;
+          f_11 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Header.version", &f_1)?;
+    verify_required_field_exists("Header.parent_hash", &f_2)?;
+    verify_required_field_exists("Header.timestamp", &f_3)?;
+    verify_required_field_exists("Header.number", &f_4)?;
+    verify_required_field_exists("Header.txs_commit", &f_5)?;
+    verify_required_field_exists("Header.txs_proposal", &f_6)?;
+    verify_required_field_exists("Header.difficulty", &f_7)?;
+    verify_required_field_exists("Header.nonce", &f_8)?;
+    verify_required_field_exists("Header.proof", &f_9)?;
+    verify_required_field_exists("Header.cellbase_id", &f_10)?;
+    verify_required_field_exists("Header.uncles_hash", &f_11)?;
+    let ret = Header {
+      version: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      parent_hash: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      timestamp: f_3.expect("auto-generated code should have checked for presence of required fields"),
+      number: f_4.expect("auto-generated code should have checked for presence of required fields"),
+      txs_commit: f_5.expect("auto-generated code should have checked for presence of required fields"),
+      txs_proposal: f_6.expect("auto-generated code should have checked for presence of required fields"),
+      difficulty: f_7.expect("auto-generated code should have checked for presence of required fields"),
+      nonce: f_8.expect("auto-generated code should have checked for presence of required fields"),
+      proof: f_9.expect("auto-generated code should have checked for presence of required fields"),
+      cellbase_id: f_10.expect("auto-generated code should have checked for presence of required fields"),
+      uncles_hash: f_11.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Header");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("version", TType::I32, 1))?;
+    o_prot.write_i32(self.version)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("parent_hash", TType::String, 2))?;
+    o_prot.write_bytes(&self.parent_hash)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("timestamp", TType::I64, 3))?;
+    o_prot.write_i64(self.timestamp)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("number", TType::I64, 4))?;
+    o_prot.write_i64(self.number)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("txs_commit", TType::String, 5))?;
+    o_prot.write_bytes(&self.txs_commit)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("txs_proposal", TType::String, 6))?;
+    o_prot.write_bytes(&self.txs_proposal)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("difficulty", TType::String, 7))?;
+    o_prot.write_bytes(&self.difficulty)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("nonce", TType::I64, 8))?;
+    o_prot.write_i64(self.nonce)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("proof", TType::String, 9))?;
+    o_prot.write_bytes(&self.proof)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("cellbase_id", TType::String, 10))?;
+    o_prot.write_bytes(&self.cellbase_id)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("uncles_hash", TType::String, 11))?;
+    o_prot.write_bytes(&self.uncles_hash)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Block
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Block {
+  pub header: Header,
+  pub transactions: Vec<Transaction>,
+}
+
+impl Block {
+  pub fn new(header: Header, transactions: Vec<Transaction>) -> Block {
+    Block {
+      header,
+      transactions,
+    }
+  }
+}
+
+impl TSerializable for Block {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Block> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Header> = None;
+    let mut f_2: Option<Vec<Transaction>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = Header::read_from_in_protocol(i_prot)?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<Transaction> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_2 = Transaction::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_2);
+          }
+          i_prot.read_list_end()?;
+          f_2 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Block.header", &f_1)?;
+    verify_required_field_exists("Block.transactions", &f_2)?;
+    let ret = Block {
+      header: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      transactions: f_2.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Block");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("header", TType::Struct, 1))?;
+    self.header.write_to_out_protocol(o_prot)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("transactions", TType::List, 2))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.transactions.len() as i32))?;
+    for e in &self.transactions {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// Transaction
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Transaction {
+  pub version: i32,
+  pub deps: Vec<OutPoint>,
+  pub inputs: Vec<CellInput>,
+  pub outputs: Vec<CellOutput>,
+}
+
+impl Transaction {
+  pub fn new(version: i32, deps: Vec<OutPoint>, inputs: Vec<CellInput>, outputs: Vec<CellOutput>) -> Transaction {
+    Transaction {
+      version,
+      deps,
+      inputs,
+      outputs,
+    }
+  }
+}
+
+impl TSerializable for Transaction {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Transaction> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<i32> = None;
+    let mut f_2: Option<Vec<OutPoint>> = None;
+    let mut f_3: Option<Vec<CellInput>> = None;
+    let mut f_4: Option<Vec<CellOutput>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_i32()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<OutPoint> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_3 = OutPoint::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_3);
+          }
+          i_prot.read_list_end()?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<CellInput> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_4 = CellInput::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_4);
+          }
+          i_prot.read_list_end()?;
+          f_3 = Some(val);
+        },
+        4 => {
+          let list_ident = i_prot.read_list_begin()?;
+          let mut val: Vec<CellOutput> = Vec::with_capacity(list_ident.size as usize);
+          for _ in 0..list_ident.size {
+            let list_elem_5 = CellOutput::read_from_in_protocol(i_prot)?;
+            val.push(list_elem_5);
+          }
+          i_prot.read_list_end()?;
+          f_4 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("Transaction.version", &f_1)?;
+    verify_required_field_exists("Transaction.deps", &f_2)?;
+    verify_required_field_exists("Transaction.inputs", &f_3)?;
+    verify_required_field_exists("Transaction.outputs", &f_4)?;
+    let ret = Transaction {
+      version: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      deps: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      inputs: f_3.expect("auto-generated code should have checked for presence of required fields"),
+      outputs: f_4.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("Transaction");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("version", TType::I32, 1))?;
+    o_prot.write_i32(self.version)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("deps", TType::List, 2))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.deps.len() as i32))?;
+    for e in &self.deps {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("inputs", TType::List, 3))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.inputs.len() as i32))?;
+    for e in &self.inputs {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("outputs", TType::List, 4))?;
+    o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, self.outputs.len() as i32))?;
+    for e in &self.outputs {
+      e.write_to_out_protocol(o_prot)?;
+    }
+    o_prot.write_list_end()?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// OutPoint
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OutPoint {
+  pub hash: Vec<u8>,
+  pub index: i32,
+}
+
+impl OutPoint {
+  pub fn new(hash: Vec<u8>, index: i32) -> OutPoint {
+    OutPoint {
+      hash,
+      index,
+    }
+  }
+}
+
+impl TSerializable for OutPoint {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<OutPoint> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Vec<u8>> = None;
+    let mut f_2: Option<i32> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_bytes()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = i_prot.read_i32()?;
+          f_2 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("OutPoint.hash", &f_1)?;
+    verify_required_field_exists("OutPoint.index", &f_2)?;
+    let ret = OutPoint {
+      hash: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      index: f_2.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("OutPoint");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("hash", TType::String, 1))?;
+    o_prot.write_bytes(&self.hash)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("index", TType::I32, 2))?;
+    o_prot.write_i32(self.index)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// CellInput
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CellInput {
+  pub hash: Vec<u8>,
+  pub index: i32,
+  pub unlock: Vec<u8>,
+}
+
+impl CellInput {
+  pub fn new(hash: Vec<u8>, index: i32, unlock: Vec<u8>) -> CellInput {
+    CellInput {
+      hash,
+      index,
+      unlock,
+    }
+  }
+}
+
+impl TSerializable for CellInput {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<CellInput> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<Vec<u8>> = None;
+    let mut f_2: Option<i32> = None;
+    let mut f_3: Option<Vec<u8>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_bytes()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = i_prot.read_i32()?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = i_prot.read_bytes()?;
+          f_3 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("CellInput.hash", &f_1)?;
+    verify_required_field_exists("CellInput.index", &f_2)?;
+    verify_required_field_exists("CellInput.unlock", &f_3)?;
+    let ret = CellInput {
+      hash: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      index: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      unlock: f_3.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("CellInput");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("hash", TType::String, 1))?;
+    o_prot.write_bytes(&self.hash)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("index", TType::I32, 2))?;
+    o_prot.write_i32(self.index)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("unlock", TType::String, 3))?;
+    o_prot.write_bytes(&self.unlock)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
+
+//
+// CellOutput
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CellOutput {
+  pub capacity: i64,
+  pub data: Vec<u8>,
+  pub lock: Vec<u8>,
+}
+
+impl CellOutput {
+  pub fn new(capacity: i64, data: Vec<u8>, lock: Vec<u8>) -> CellOutput {
+    CellOutput {
+      capacity,
+      data,
+      lock,
+    }
+  }
+}
+
+impl TSerializable for CellOutput {
+  fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<CellOutput> {
+    i_prot.read_struct_begin()?;
+    let mut f_1: Option<i64> = None;
+    let mut f_2: Option<Vec<u8>> = None;
+    let mut f_3: Option<Vec<u8>> = None;
+    loop {
+      let field_ident = i_prot.read_field_begin()?;
+      if field_ident.field_type == TType::Stop {
+        break;
+      }
+      let field_id = field_id(&field_ident)?;
+      match field_id {
+        1 => {
+          let val = i_prot.read_i64()?;
+          f_1 = Some(val);
+        },
+        2 => {
+          let val = i_prot.read_bytes()?;
+          f_2 = Some(val);
+        },
+        3 => {
+          let val = i_prot.read_bytes()?;
+          f_3 = Some(val);
+        },
+        _ => {
+          i_prot.skip(field_ident.field_type)?;
+        },
+      };
+      i_prot.read_field_end()?;
+    }
+    i_prot.read_struct_end()?;
+    verify_required_field_exists("CellOutput.capacity", &f_1)?;
+    verify_required_field_exists("CellOutput.data", &f_2)?;
+    verify_required_field_exists("CellOutput.lock", &f_3)?;
+    let ret = CellOutput {
+      capacity: f_1.expect("auto-generated code should have checked for presence of required fields"),
+      data: f_2.expect("auto-generated code should have checked for presence of required fields"),
+      lock: f_3.expect("auto-generated code should have checked for presence of required fields"),
+    };
+    Ok(ret)
+  }
+  fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+    let struct_ident = TStructIdentifier::new("CellOutput");
+    o_prot.write_struct_begin(&struct_ident)?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("capacity", TType::I64, 1))?;
+    o_prot.write_i64(self.capacity)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("data", TType::String, 2))?;
+    o_prot.write_bytes(&self.data)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_begin(&TFieldIdentifier::new("lock", TType::String, 3))?;
+    o_prot.write_bytes(&self.lock)?;
+    o_prot.write_field_end()?;
+    o_prot.write_field_stop()?;
+    o_prot.write_struct_end()
+  }
+}
@@ -0,0 +1,241 @@
+//! XDR (RFC 4506) wire types for `schemas/bench.x`.
+//!
+//! Every item is big-endian and padded with zeros to a multiple of 4 bytes.
+//! Hashes are fixed `opaque[32]`, byte vectors are variable `opaque<>` and
+//! lists are variable arrays, both with a 4-byte length prefix. Decoding
+//! rejects non-zero padding and trailing bytes, so the encoding is canonical.
+
+use crate::read::{array, take, vec, UnexpectedEof};
+
+pub struct Header {
+    pub version: u32,
+    pub parent_hash: [u8; 32],
+    pub timestamp: u64,
+    pub number: u64,
+    pub txs_commit: [u8; 32],
+    pub txs_proposal: [u8; 32],
+    pub difficulty: [u8; 32],
+    pub nonce: u64,
+    pub proof: Vec<u8>,
+    pub cellbase_id: [u8; 32],
+    pub uncles_hash: [u8; 32],
+}
+
+pub struct Block {
+    pub header: Header,
+    pub transactions: Vec<Transaction>,
+}
+
+pub struct Transaction {
+    pub version: u32,
+    pub deps: Vec<OutPoint>,
+    pub inputs: Vec<CellInput>,
+    pub outputs: Vec<CellOutput>,
+}
+
+pub struct OutPoint {
+    pub hash: [u8; 32],
+    pub index: u32,
+}
+
+pub struct CellInput {
+    pub hash: [u8; 32],
+    pub index: u32,
+    pub unlock: Vec<u8>,
+}
+
+pub struct CellOutput {
+    pub capacity: u64,
+    pub data: Vec<u8>,
+    pub lock: [u8; 32],
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    UnexpectedEof,
+    NonZeroPadding,
+    TrailingBytes,
+}
+
+impl From<UnexpectedEof> for Error {
+    fn from(_: UnexpectedEof) -> Self {
+        Error::UnexpectedEof
+    }
+}
+
+pub trait Encode {
+    fn encode(&self, out: &mut Vec<u8>);
+}
+
+pub trait Decode: Sized {
+    fn decode(input: &mut &[u8]) -> Result<Self, Error>;
+}
+
+pub fn to_bytes<T: Encode>(value: &T) -> Vec<u8> {
+    let mut out = Vec::new();
+    value.encode(&mut out);
+    out
+}
+
+pub fn from_bytes<T: Decode>(mut data: &[u8]) -> Result<T, Error> {
+    let value = T::decode(&mut data)?;
+    if data.is_empty() {
+        Ok(value)
+    } else {
+        Err(Error::TrailingBytes)
+    }
+}
+
+impl Encode for Header {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.version.to_be_bytes());
+        out.extend_from_slice(&self.parent_hash);
+        out.extend_from_slice(&self.timestamp.to_be_bytes());
+        out.extend_from_slice(&self.number.to_be_bytes());
+        out.extend_from_slice(&self.txs_commit);
+        out.extend_from_slice(&self.txs_proposal);
+        out.extend_from_slice(&self.difficulty);
+        out.extend_from_slice(&self.nonce.to_be_bytes());
+        encode_opaque(&self.proof, out);
+        out.extend_from_slice(&self.cellbase_id);
+        out.extend_from_slice(&self.uncles_hash);
+    }
+}
+
+impl Decode for Header {
+    fn decode(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(Header {
+            version: u32::from_be_bytes(array(input)?),
+            parent_hash: array(input)?,
+            timestamp: u64::from_be_bytes(array(input)?),
+            number: u64::from_be_bytes(array(input)?),
+            txs_commit: array(input)?,
+            txs_proposal: array(input)?,
+            difficulty: array(input)?,
+            nonce: u64::from_be_bytes(array(input)?),
+            proof: decode_opaque(input)?,
+            cellbase_id: array(input)?,
+            uncles_hash: array(input)?,
+        })
+    }
+}
+
+impl Encode for Block {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.header.encode(out);
+        encode_array(&self.transactions, out);
+    }
+}
+
+impl Decode for Block {
+    fn decode(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(Block {
+            header: Header::decode(input)?,
+            transactions: decode_array(input)?,
+        })
+    }
+}
+
+impl Encode for Transaction {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.version.to_be_bytes());
+        encode_array(&self.deps, out);
+        encode_array(&self.inputs, out);
+        encode_array(&self.outputs, out);
+    }
+}
+
+impl Decode for Transaction {
+    fn decode(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(Transaction {
+            version: u32::from_be_bytes(array(input)?),
+            deps: decode_array(input)?,
+            inputs: decode_array(input)?,
+            outputs: decode_array(input)?,
+        })
+    }
+}
+
+impl Encode for OutPoint {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.hash);
+        out.extend_from_slice(&self.index.to_be_bytes());
+    }
+}
+
+impl Decode for OutPoint {
+    fn decode(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(OutPoint {
+            hash: array(input)?,
+            index: u32::from_be_bytes(array(input)?),
+        })
+    }
+}
+
+impl Encode for CellInput {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.hash);
+        out.extend_from_slice(&self.index.to_be_bytes());
+        encode_opaque(&self.unlock, out);
+    }
+}
+
+impl Decode for CellInput {
+    fn decode(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(CellInput {
+            hash: array(input)?,
+            index: u32::from_be_bytes(array(input)?),
+            unlock: decode_opaque(input)?,
+        })
+    }
+}
+
+impl Encode for CellOutput {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.capacity.to_be_bytes());
+        encode_opaque(&self.data, out);
+        out.extend_from_slice(&self.lock);
+    }
+}
+
+impl Decode for CellOutput {
+    fn decode(input: &mut &[u8]) -> Result<Self, Error> {
+        Ok(CellOutput {
+            capacity: u64::from_be_bytes(array(input)?),
+            data: decode_opaque(input)?,
+            lock: array(input)?,
+        })
+    }
+}
+
+fn padding(len: usize) -> usize {
+    (4 - len % 4) % 4
+}
+
+fn encode_opaque(bytes: &[u8], out: &mut Vec<u8>) {
+    out.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    out.extend_from_slice(bytes);
+    out.extend_from_slice(&[0; 3][..padding(bytes.len())]);
+}
+
+fn encode_array<T: Encode>(items: &[T], out: &mut Vec<u8>) {
+    out.extend_from_slice(&(items.len() as u32).to_be_bytes());
+    for item in items {
+        item.encode(out);
+    }
+}
+
+fn decode_opaque(input: &mut &[u8]) -> Result<Vec<u8>, Error> {
+    let len = u32::from_be_bytes(array(input)?) as usize;
+    let bytes = take(input, len)?.to_vec();
+    if take(input, padding(len))?.iter().any(|&byte| byte != 0) {
+        return Err(Error::NonZeroPadding);
+    }
+    Ok(bytes)
+}
+
+fn decode_array<T: Decode>(input: &mut &[u8]) -> Result<Vec<T>, Error> {
+    let len = u32::from_be_bytes(array(input)?) as usize;
+    // Every item takes at least 4 bytes.
+    vec(input, len, 4, T::decode)
+}
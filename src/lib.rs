@@ -1,12 +1,125 @@
+pub mod alloc_counter;
+#[cfg(feature = "avro")]
+pub mod bench_avro;
+#[cfg(feature = "bcs")]
+pub mod bench_bcs;
+#[cfg(feature = "bitcode")]
+pub mod bench_bitcode;
+#[cfg(feature = "bitcoin")]
+pub mod bench_bitcoin;
+#[cfg(feature = "borsh")]
+pub mod bench_borsh;
+#[cfg(feature = "bson")]
+pub mod bench_bson;
+#[cfg(feature = "der")]
+pub mod bench_der;
+#[cfg(feature = "flatbuffers")]
+#[allow(warnings)]
 pub mod bench_flatbuffers;
+#[cfg(feature = "flatbuffers")]
+#[allow(warnings)]
+pub mod bench_flatbuffers_struct;
+#[cfg(feature = "flexbuffers")]
+pub mod bench_flexbuffers;
+#[cfg(feature = "json")]
+pub mod bench_json;
+#[cfg(feature = "molecule")]
+#[allow(warnings)]
 pub mod bench_molecule;
+#[cfg(feature = "molecule")]
+#[allow(warnings)]
+pub mod bench_molecule_struct;
+#[cfg(feature = "postcard")]
+pub mod bench_postcard;
+#[cfg(feature = "prost")]
+pub mod bench_prost;
+#[cfg(feature = "prost")]
+pub mod bench_prost_fixed;
+#[cfg(feature = "protobuf")]
+#[allow(warnings)]
 pub mod bench_protobuf;
+#[cfg(feature = "quick-protobuf")]
+pub mod bench_quick_protobuf;
+#[cfg(feature = "raw")]
+pub mod bench_raw;
+#[cfg(feature = "rkyv")]
+pub mod bench_rkyv;
+#[cfg(feature = "sbe")]
+pub mod bench_sbe;
+#[cfg(feature = "scale")]
+pub mod bench_scale;
+#[cfg(feature = "ssz")]
+pub mod bench_ssz;
+#[cfg(feature = "thrift")]
+pub mod bench_thrift;
+#[cfg(feature = "xdr")]
+pub mod bench_xdr;
+#[cfg(any(feature = "bitcoin", feature = "raw", feature = "sbe", feature = "xdr"))]
+mod read;
 
+#[cfg(feature = "avro")]
+use apache_avro::types::Value as AvroValue;
+#[cfg(feature = "avro")]
+use apache_avro::{from_avro_datum, to_avro_datum, Reader as AvroReader, Writer as AvroWriter};
+#[cfg(feature = "avro")]
+use bench_avro::schemas as avro_schemas;
+#[cfg(feature = "bcs")]
+use bench_bcs::{
+    Block as BcsBlock, CellInput as BcsCellInput, CellOutput as BcsCellOutput, Header as BcsHeader,
+    OutPoint as BcsOutPoint, Transaction as BcsTransaction,
+};
+#[cfg(feature = "bitcode")]
+use bench_bitcode::{
+    Block as BitcodeBlock, CellInput as BitcodeCellInput, CellOutput as BitcodeCellOutput,
+    Header as BitcodeHeader, OutPoint as BitcodeOutPoint, Transaction as BitcodeTransaction,
+};
+#[cfg(feature = "bitcoin")]
+use bench_bitcoin::{
+    Block as BtcBlock, CellInput as BtcCellInput, CellOutput as BtcCellOutput, Header as BtcHeader,
+    OutPoint as BtcOutPoint, Transaction as BtcTransaction,
+};
+#[cfg(feature = "borsh")]
+use bench_borsh::{
+    Block as BorshBlock, CellInput as BorshCellInput, CellOutput as BorshCellOutput,
+    Header as BorshHeader, OutPoint as BorshOutPoint, Transaction as BorshTransaction,
+};
+#[cfg(feature = "bson")]
+use bench_bson::{
+    Block as BsonBlock, CellInput as BsonCellInput, CellOutput as BsonCellOutput,
+    Header as BsonHeader, OutPoint as BsonOutPoint, Transaction as BsonTransaction,
+};
+#[cfg(feature = "der")]
+use bench_der::{
+    Block as DerBlock, Byte32 as DerByte32, CellInput as DerCellInput, CellOutput as DerCellOutput,
+    Header as DerHeader, OutPoint as DerOutPoint, Transaction as DerTransaction,
+};
+#[cfg(feature = "flatbuffers")]
 use bench_flatbuffers::{
     Block as FbsBlock, BlockBuilder, CellInput as FbsCellInput, CellInputBuilder,
     CellOutput as FbsCellOutput, CellOutputBuilder, Header as FbsHeader, HeaderBuilder,
     OutPoint as FbsOutPoint, OutPointBuilder, Transaction as FbsTransaction, TransactionBuilder,
 };
+#[cfg(feature = "flatbuffers")]
+use bench_flatbuffers_struct::{
+    Block as FbsStructBlock, BlockBuilder as FbsStructBlockBuilder, Byte32 as FbsStructByte32,
+    CellInput as FbsStructCellInput, CellInputBuilder as FbsStructCellInputBuilder,
+    CellOutput as FbsStructCellOutput, CellOutputBuilder as FbsStructCellOutputBuilder,
+    Header as FbsStructHeader, HeaderBuilder as FbsStructHeaderBuilder,
+    OutPoint as FbsStructOutPoint, Transaction as FbsStructTransaction,
+    TransactionBuilder as FbsStructTransactionBuilder,
+};
+#[cfg(feature = "flexbuffers")]
+use bench_flexbuffers::{
+    Block as FlexBlock, CellInput as FlexCellInput, CellOutput as FlexCellOutput,
+    Header as FlexHeader, OutPoint as FlexOutPoint, Transaction as FlexTransaction,
+};
+#[cfg(feature = "json")]
+use bench_json::{
+    Block as JsonBlock, Byte32 as JsonByte32, CellInput as JsonCellInput,
+    CellOutput as JsonCellOutput, Header as JsonHeader, JsonBytes, OutPoint as JsonOutPoint,
+    Transaction as JsonTransaction,
+};
+#[cfg(feature = "molecule")]
 use bench_molecule::{
     Block as MolBlock, BlockReader as MolBlockReader, Byte32, Bytes as MolBytes,
     CellInput as MolCellInput, CellInputReader as MolCellInputReader, CellInputVec,
@@ -15,30 +128,126 @@ use bench_molecule::{
     OutPointReader as MolOutPointReader, OutPointVec, Transaction as MolTransaction,
     TransactionReader as MolTransactionReader, TransactionVec, Uint32, Uint64,
 };
+#[cfg(feature = "molecule")]
+use bench_molecule_struct::{
+    Block as MolStructBlock, BlockReader as MolStructBlockReader, Byte32 as MolStructByte32,
+    Bytes as MolStructBytes, CellInput as MolStructCellInput,
+    CellInputReader as MolStructCellInputReader, CellInputVec as MolStructCellInputVec,
+    CellOutput as MolStructCellOutput, CellOutputReader as MolStructCellOutputReader,
+    CellOutputVec as MolStructCellOutputVec, Header as MolStructHeader,
+    HeaderReader as MolStructHeaderReader, OutPoint as MolStructOutPoint,
+    OutPointReader as MolStructOutPointReader, OutPointVec as MolStructOutPointVec,
+    RawHeader as MolStructRawHeader, Transaction as MolStructTransaction,
+    TransactionReader as MolStructTransactionReader, TransactionVec as MolStructTransactionVec,
+    Uint32 as MolStructUint32, Uint64 as MolStructUint64,
+};
+#[cfg(feature = "postcard")]
+use bench_postcard::{
+    Block as PostcardBlock, CellInput as PostcardCellInput, CellOutput as PostcardCellOutput,
+    Header as PostcardHeader, HeaplessHeader as PostcardHeaplessHeader,
+    OutPoint as PostcardOutPoint, Transaction as PostcardTransaction,
+};
+#[cfg(feature = "prost")]
+use bench_prost::{
+    Block as ProstBlock, CellInput as ProstCellInput, CellOutput as ProstCellOutput,
+    Header as ProstHeader, OutPoint as ProstOutPoint, Transaction as ProstTransaction,
+};
+#[cfg(feature = "prost")]
+use bench_prost_fixed::{
+    Block as ProstFixedBlock, CellInput as ProstFixedCellInput, CellOutput as ProstFixedCellOutput,
+    Header as ProstFixedHeader, OutPoint as ProstFixedOutPoint,
+    Transaction as ProstFixedTransaction,
+};
+#[cfg(feature = "protobuf")]
 use bench_protobuf::{
     Block as ProtobufBlock, CellInput as ProtobufCellInput, CellOutput as ProtobufCellOutput,
     Header as ProtobufHeader, OutPoint as ProtobufOutPoint, Transaction as ProtobufTransaction,
 };
+#[cfg(feature = "quick-protobuf")]
+use bench_quick_protobuf::{
+    Block as QuickBlock, CellInput as QuickCellInput, CellOutput as QuickCellOutput,
+    Header as QuickHeader, OutPoint as QuickOutPoint, Transaction as QuickTransaction,
+};
+#[cfg(feature = "raw")]
+use bench_raw::Raw;
+#[cfg(feature = "rkyv")]
+use bench_rkyv::AsByte32;
+#[cfg(feature = "sbe")]
+use bench_sbe::{
+    Block as SbeBlock, CellInput as SbeCellInput, CellOutput as SbeCellOutput, Header as SbeHeader,
+    OutPoint as SbeOutPoint, Transaction as SbeTransaction,
+};
+#[cfg(feature = "scale")]
+use bench_scale::{
+    Block as ScaleBlock, CellInput as ScaleCellInput, CellOutput as ScaleCellOutput,
+    Header as ScaleHeader, OutPoint as ScaleOutPoint, Transaction as ScaleTransaction,
+};
+#[cfg(feature = "ssz")]
+use bench_ssz::{
+    Block as SszBlock, CellInput as SszCellInput, CellOutput as SszCellOutput, CheckLimits,
+    HashTreeRoot, Header as SszHeader, OutPoint as SszOutPoint, Transaction as SszTransaction,
+};
+#[cfg(feature = "thrift")]
+use bench_thrift::{
+    Block as ThriftBlock, CellInput as ThriftCellInput, CellOutput as ThriftCellOutput,
+    Header as ThriftHeader, OutPoint as ThriftOutPoint, Transaction as ThriftTransaction,
+};
+#[cfg(feature = "xdr")]
+use bench_xdr::{
+    Block as XdrBlock, CellInput as XdrCellInput, CellOutput as XdrCellOutput, Header as XdrHeader,
+    OutPoint as XdrOutPoint, Transaction as XdrTransaction,
+};
 use bigint::{H256, U256};
+#[cfg(feature = "borsh")]
+use borsh::BorshDeserialize;
+#[cfg(feature = "der")]
+use der::asn1::OctetString;
+#[cfg(feature = "der")]
+use der::{Decode as _, Encode as _};
+#[cfg(feature = "flatbuffers")]
 use flatbuffers::{get_root, FlatBufferBuilder};
+#[cfg(feature = "molecule")]
 use molecule::prelude::{Builder, Entity, Reader};
-use protobuf::{parse_from_bytes, Message};
+#[cfg(feature = "scale")]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(feature = "prost")]
+use prost::Message as _;
+#[cfg(feature = "protobuf")]
+use protobuf::Message;
+#[cfg(feature = "quick-protobuf")]
+use quick_protobuf::{BytesReader, MessageRead, MessageWrite, Writer};
 use rand::distributions::Standard;
 use rand::{thread_rng, Rng};
-use std::borrow::Borrow;
+#[cfg(feature = "rkyv")]
+use rkyv::{
+    AlignedVec, Archive, Deserialize as RkyvDeserialize, Infallible, Serialize as RkyvSerialize,
+};
+#[cfg(feature = "ssz")]
+use ssz::{Decode as _, Encode as _};
+#[cfg(feature = "quick-protobuf")]
+use std::borrow::Cow;
+#[cfg(feature = "molecule")]
 use std::convert::TryInto;
+#[cfg(feature = "thrift")]
+use thrift::protocol::{
+    TBinaryInputProtocol, TBinaryOutputProtocol, TCompactInputProtocol, TCompactOutputProtocol,
+    TSerializable,
+};
 
+#[cfg(feature = "flatbuffers")]
 pub struct FlatbuffersVectorIterator<'a, T: flatbuffers::Follow<'a> + 'a> {
     vector: flatbuffers::Vector<'a, T>,
     counter: usize,
 }
 
+#[cfg(feature = "flatbuffers")]
 impl<'a, T: flatbuffers::Follow<'a> + 'a> FlatbuffersVectorIterator<'a, T> {
     pub fn new(vector: flatbuffers::Vector<'a, T>) -> Self {
         Self { vector, counter: 0 }
     }
 }
 
+#[cfg(feature = "flatbuffers")]
 impl<'a, T: flatbuffers::Follow<'a> + 'a> Iterator for FlatbuffersVectorIterator<'a, T> {
     type Item = T::Inner;
 
@@ -54,32 +263,58 @@ impl<'a, T: flatbuffers::Follow<'a> + 'a> Iterator for FlatbuffersVectorIterator
 }
 
 #[derive(Default, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(Archive, RkyvSerialize, RkyvDeserialize),
+    archive(check_bytes)
+)]
 pub struct Header {
     pub version: u32,
+    #[cfg_attr(feature = "rkyv", with(AsByte32))]
     pub parent_hash: H256,
     pub timestamp: u64,
     pub number: u64,
+    #[cfg_attr(feature = "rkyv", with(AsByte32))]
     pub txs_commit: H256,
+    #[cfg_attr(feature = "rkyv", with(AsByte32))]
     pub txs_proposal: H256,
+    #[cfg_attr(feature = "rkyv", with(AsByte32))]
     pub difficulty: U256,
+    #[cfg_attr(feature = "rkyv", with(AsByte32))]
     pub cellbase_id: H256,
+    #[cfg_attr(feature = "rkyv", with(AsByte32))]
     pub uncles_hash: H256,
     pub seal: Seal,
 }
 
 #[derive(Default, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(Archive, RkyvSerialize, RkyvDeserialize),
+    archive(check_bytes)
+)]
 pub struct Seal {
     pub nonce: u64,
     pub proof: Vec<u8>,
 }
 
 #[derive(Default, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(Archive, RkyvSerialize, RkyvDeserialize),
+    archive(check_bytes)
+)]
 pub struct Block {
     pub header: Header,
     pub transactions: Vec<Transaction>,
 }
 
 #[derive(Default, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(Archive, RkyvSerialize, RkyvDeserialize),
+    archive(check_bytes)
+)]
 pub struct Transaction {
     pub version: u32,
     pub deps: Vec<OutPoint>,
@@ -88,24 +323,42 @@ pub struct Transaction {
 }
 
 #[derive(Default, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(Archive, RkyvSerialize, RkyvDeserialize),
+    archive(check_bytes)
+)]
 pub struct OutPoint {
+    #[cfg_attr(feature = "rkyv", with(AsByte32))]
     pub hash: H256,
     pub index: u32,
 }
 
 #[derive(Default, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(Archive, RkyvSerialize, RkyvDeserialize),
+    archive(check_bytes)
+)]
 pub struct CellInput {
     pub previous_output: OutPoint,
     pub unlock: Vec<u8>,
 }
 
 #[derive(Default, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(Archive, RkyvSerialize, RkyvDeserialize),
+    archive(check_bytes)
+)]
 pub struct CellOutput {
     pub capacity: u64,
     pub data: Vec<u8>,
+    #[cfg_attr(feature = "rkyv", with(AsByte32))]
     pub lock: H256,
 }
 
+#[cfg(feature = "flatbuffers")]
 impl<'a> From<&'a FbsHeader<'a>> for Header {
     fn from(header: &FbsHeader<'a>) -> Self {
         Header {
@@ -126,6 +379,7 @@ impl<'a> From<&'a FbsHeader<'a>> for Header {
     }
 }
 
+#[cfg(feature = "protobuf")]
 impl<'a> From<&'a ProtobufHeader> for Header {
     fn from(header: &ProtobufHeader) -> Self {
         Header {
@@ -146,6 +400,7 @@ impl<'a> From<&'a ProtobufHeader> for Header {
     }
 }
 
+#[cfg(feature = "protobuf")]
 impl<'a> From<&'a Header> for ProtobufHeader {
     fn from(header: &Header) -> Self {
         let mut result = ProtobufHeader::new();
@@ -164,6 +419,7 @@ impl<'a> From<&'a Header> for ProtobufHeader {
     }
 }
 
+#[cfg(feature = "molecule")]
 impl From<MolHeaderReader<'_>> for Header {
     fn from(header: MolHeaderReader) -> Self {
         Header {
@@ -184,656 +440,6517 @@ impl From<MolHeaderReader<'_>> for Header {
     }
 }
 
-impl Header {
-    pub fn random() -> Self {
+#[cfg(feature = "scale")]
+impl From<ScaleHeader> for Header {
+    fn from(header: ScaleHeader) -> Self {
         Header {
-            version: thread_rng().gen_range(1, 10),
-            parent_hash: H256::random(),
-            timestamp: thread_rng().gen_range(1500000000, 1600000000),
-            number: thread_rng().gen_range(0, 100000),
-            txs_commit: H256::random(),
-            txs_proposal: H256::random(),
-            difficulty: H256::random().into(),
-            cellbase_id: H256::random(),
-            uncles_hash: H256::random(),
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: H256::from(header.difficulty).into(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
             seal: Seal {
-                nonce: thread_rng().gen_range(0, 100000),
-                proof: H256::random().to_vec(),
+                nonce: header.nonce,
+                proof: header.proof,
             },
         }
     }
+}
 
-    pub fn to_flatbuffers(&self) -> Vec<u8> {
-        let fbb = &mut FlatBufferBuilder::new();
-        let parent_hash = fbb.create_vector(&self.parent_hash);
-        let txs_commit = fbb.create_vector(&self.txs_commit);
-        let txs_proposal = fbb.create_vector(&self.txs_proposal);
-        let difficulty = fbb.create_vector(&<[u8; 32]>::from(self.difficulty));
-        let proof = fbb.create_vector(&self.seal.proof);
-        let cellbase_id = fbb.create_vector(&self.cellbase_id);
-        let uncles_hash = fbb.create_vector(&self.uncles_hash);
-
-        let message = {
-            let mut builder = HeaderBuilder::new(fbb);
-            builder.add_version(self.version);
-            builder.add_parent_hash(parent_hash);
-            builder.add_timestamp(self.timestamp);
-            builder.add_number(self.number);
-            builder.add_txs_commit(txs_commit);
-            builder.add_txs_proposal(txs_proposal);
-            builder.add_difficulty(difficulty);
-            builder.add_nonce(self.seal.nonce);
-            builder.add_proof(proof);
-            builder.add_cellbase_id(cellbase_id);
-            builder.add_uncles_hash(uncles_hash);
-            builder.finish()
-        };
-        fbb.finish(message, None);
-        fbb.finished_data().to_vec()
+#[cfg(feature = "scale")]
+impl<'a> From<&'a Header> for ScaleHeader {
+    fn from(header: &Header) -> Self {
+        ScaleHeader {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: header.difficulty.into(),
+            nonce: header.seal.nonce,
+            proof: header.seal.proof.to_vec(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+        }
     }
+}
 
-    pub fn from_flatbuffers(data: &[u8]) -> Self {
-        get_root::<FbsHeader>(data).borrow().into()
+#[cfg(feature = "borsh")]
+impl From<BorshHeader> for Header {
+    fn from(header: BorshHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: H256::from(header.difficulty).into(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof,
+            },
+        }
     }
+}
 
-    pub fn to_protobuf(&self) -> Vec<u8> {
-        let header: ProtobufHeader = self.into();
-        header.write_to_bytes().unwrap()
+#[cfg(feature = "borsh")]
+impl<'a> From<&'a Header> for BorshHeader {
+    fn from(header: &Header) -> Self {
+        BorshHeader {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: header.difficulty.into(),
+            nonce: header.seal.nonce,
+            proof: header.seal.proof.to_vec(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+        }
     }
+}
 
-    pub fn from_protobuf(data: &[u8]) -> Self {
-        let header = parse_from_bytes::<ProtobufHeader>(data).unwrap();
-        header.borrow().into()
+#[cfg(feature = "ssz")]
+impl From<SszHeader> for Header {
+    fn from(header: SszHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: H256::from(header.difficulty).into(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof,
+            },
+        }
     }
+}
 
-    pub fn to_molecule(&self) -> Vec<u8> {
-        MolHeader::new_builder()
-            .version(Uint32::from_slice(&self.version.to_le_bytes()).unwrap())
-            .parent_hash(Byte32::from_slice(&self.parent_hash).unwrap())
-            .timestamp(Uint64::from_slice(&self.timestamp.to_le_bytes()).unwrap())
-            .number(Uint64::from_slice(&self.number.to_le_bytes()).unwrap())
-            .txs_commit(Byte32::from_slice(&self.txs_commit).unwrap())
-            .txs_proposal(Byte32::from_slice(&self.txs_proposal).unwrap())
-            .difficulty(Byte32::from_slice(&H256::from(self.difficulty)).unwrap())
-            .nonce(Uint64::from_slice(&self.seal.nonce.to_le_bytes()).unwrap())
-            .proof(
-                MolBytes::new_builder()
-                    .extend(self.seal.proof.clone().into_iter().map(Into::into))
-                    .build(),
-            )
-            .cellbase_id(Byte32::from_slice(&self.cellbase_id).unwrap())
-            .uncles_hash(Byte32::from_slice(&self.uncles_hash).unwrap())
-            .build()
-            .as_slice()
-            .into()
+#[cfg(feature = "ssz")]
+impl<'a> From<&'a Header> for SszHeader {
+    fn from(header: &Header) -> Self {
+        SszHeader {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: header.difficulty.into(),
+            nonce: header.seal.nonce,
+            proof: header.seal.proof.to_vec(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+        }
     }
+}
 
-    pub fn from_molecule(data: &[u8]) -> Self {
-        let header = MolHeaderReader::from_slice(data).unwrap();
-        header.into()
+#[cfg(feature = "prost")]
+impl From<ProstHeader> for Header {
+    fn from(header: ProstHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: H256::from_slice(&header.parent_hash),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: H256::from_slice(&header.txs_commit),
+            txs_proposal: H256::from_slice(&header.txs_proposal),
+            difficulty: H256::from_slice(&header.difficulty).into(),
+            cellbase_id: H256::from_slice(&header.cellbase_id),
+            uncles_hash: H256::from_slice(&header.uncles_hash),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof,
+            },
+        }
     }
 }
 
-impl<'a> From<&'a FbsBlock<'a>> for Block {
-    fn from(block: &FbsBlock<'a>) -> Self {
-        Block {
-            header: block.header().unwrap().borrow().into(),
-            transactions: FlatbuffersVectorIterator::new(block.transactions().unwrap())
-                .map(Into::into)
-                .collect(),
+#[cfg(feature = "prost")]
+impl<'a> From<&'a Header> for ProstHeader {
+    fn from(header: &Header) -> Self {
+        ProstHeader {
+            version: header.version,
+            parent_hash: header.parent_hash.to_vec(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.to_vec(),
+            txs_proposal: header.txs_proposal.to_vec(),
+            difficulty: <[u8; 32]>::from(header.difficulty).to_vec(),
+            nonce: header.seal.nonce,
+            proof: header.seal.proof.to_vec(),
+            cellbase_id: header.cellbase_id.to_vec(),
+            uncles_hash: header.uncles_hash.to_vec(),
         }
     }
 }
 
-impl<'a> From<&'a Block> for ProtobufBlock {
-    fn from(block: &Block) -> Self {
-        let mut result = ProtobufBlock::new();
-        result.set_header(block.header.borrow().into());
-        result.set_transactions(block.transactions.iter().map(Into::into).collect());
-        result
+#[cfg(feature = "quick-protobuf")]
+impl From<QuickHeader<'_>> for Header {
+    fn from(header: QuickHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: H256::from_slice(&header.parent_hash),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: H256::from_slice(&header.txs_commit),
+            txs_proposal: H256::from_slice(&header.txs_proposal),
+            difficulty: H256::from_slice(&header.difficulty).into(),
+            cellbase_id: H256::from_slice(&header.cellbase_id),
+            uncles_hash: H256::from_slice(&header.uncles_hash),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof.into_owned(),
+            },
+        }
     }
 }
 
-impl<'a> From<&'a ProtobufBlock> for Block {
-    fn from(block: &ProtobufBlock) -> Self {
-        Block {
-            header: block.get_header().borrow().into(),
-            transactions: block.get_transactions().iter().map(Into::into).collect(),
+#[cfg(feature = "quick-protobuf")]
+impl<'a> From<&'a Header> for QuickHeader<'a> {
+    fn from(header: &'a Header) -> Self {
+        QuickHeader {
+            version: header.version,
+            parent_hash: Cow::Borrowed(&header.parent_hash),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: Cow::Borrowed(&header.txs_commit),
+            txs_proposal: Cow::Borrowed(&header.txs_proposal),
+            difficulty: Cow::Owned(<[u8; 32]>::from(header.difficulty).to_vec()),
+            nonce: header.seal.nonce,
+            proof: Cow::Borrowed(&header.seal.proof),
+            cellbase_id: Cow::Borrowed(&header.cellbase_id),
+            uncles_hash: Cow::Borrowed(&header.uncles_hash),
         }
     }
 }
 
-impl From<MolBlockReader<'_>> for Block {
-    fn from(block: MolBlockReader) -> Self {
-        Block {
-            header: block.header().into(),
-            transactions: block.transactions().iter().map(Into::into).collect(),
+#[cfg(feature = "avro")]
+impl From<AvroValue> for Header {
+    fn from(value: AvroValue) -> Self {
+        let mut fields = bench_avro::record(value);
+        let mut hash = |name| H256::from_slice(&bench_avro::fixed(fields.take(name)));
+        let parent_hash = hash("parent_hash");
+        let txs_commit = hash("txs_commit");
+        let txs_proposal = hash("txs_proposal");
+        let difficulty = hash("difficulty").into();
+        let cellbase_id = hash("cellbase_id");
+        let uncles_hash = hash("uncles_hash");
+        let version = bench_avro::long(fields.take("version")) as u32;
+        let timestamp = bench_avro::long(fields.take("timestamp")) as u64;
+        let number = bench_avro::long(fields.take("number")) as u64;
+        let nonce = bench_avro::long(fields.take("nonce")) as u64;
+        let proof = bench_avro::bytes(fields.take("proof"));
+
+        Header {
+            version,
+            parent_hash,
+            timestamp,
+            number,
+            txs_commit,
+            txs_proposal,
+            difficulty,
+            cellbase_id,
+            uncles_hash,
+            seal: Seal { nonce, proof },
         }
     }
 }
 
-impl Block {
-    pub fn random(transactions_size: usize, io_size: usize) -> Self {
-        Block {
-            header: Header::random(),
-            transactions: (0..transactions_size)
-                .map(|_| Transaction::random(io_size))
-                .collect(),
+#[cfg(feature = "avro")]
+impl<'a> From<&'a Header> for AvroValue {
+    fn from(header: &Header) -> Self {
+        AvroValue::Record(vec![
+            ("version".into(), AvroValue::Long(header.version.into())),
+            (
+                "parent_hash".into(),
+                AvroValue::Fixed(32, header.parent_hash.to_vec()),
+            ),
+            ("timestamp".into(), AvroValue::Long(header.timestamp as i64)),
+            ("number".into(), AvroValue::Long(header.number as i64)),
+            (
+                "txs_commit".into(),
+                AvroValue::Fixed(32, header.txs_commit.to_vec()),
+            ),
+            (
+                "txs_proposal".into(),
+                AvroValue::Fixed(32, header.txs_proposal.to_vec()),
+            ),
+            (
+                "difficulty".into(),
+                AvroValue::Fixed(32, <[u8; 32]>::from(header.difficulty).to_vec()),
+            ),
+            ("nonce".into(), AvroValue::Long(header.seal.nonce as i64)),
+            ("proof".into(), AvroValue::Bytes(header.seal.proof.to_vec())),
+            (
+                "cellbase_id".into(),
+                AvroValue::Fixed(32, header.cellbase_id.to_vec()),
+            ),
+            (
+                "uncles_hash".into(),
+                AvroValue::Fixed(32, header.uncles_hash.to_vec()),
+            ),
+        ])
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<ThriftHeader> for Header {
+    fn from(header: ThriftHeader) -> Self {
+        Header {
+            version: header.version as u32,
+            parent_hash: H256::from_slice(&header.parent_hash),
+            timestamp: header.timestamp as u64,
+            number: header.number as u64,
+            txs_commit: H256::from_slice(&header.txs_commit),
+            txs_proposal: H256::from_slice(&header.txs_proposal),
+            difficulty: H256::from_slice(&header.difficulty).into(),
+            cellbase_id: H256::from_slice(&header.cellbase_id),
+            uncles_hash: H256::from_slice(&header.uncles_hash),
+            seal: Seal {
+                nonce: header.nonce as u64,
+                proof: header.proof,
+            },
         }
     }
+}
 
-    pub fn to_flatbuffers(&self) -> Vec<u8> {
-        let fbb = &mut FlatBufferBuilder::new();
+#[cfg(feature = "thrift")]
+impl<'a> From<&'a Header> for ThriftHeader {
+    fn from(header: &Header) -> Self {
+        ThriftHeader {
+            version: header.version as i32,
+            parent_hash: header.parent_hash.to_vec(),
+            timestamp: header.timestamp as i64,
+            number: header.number as i64,
+            txs_commit: header.txs_commit.to_vec(),
+            txs_proposal: header.txs_proposal.to_vec(),
+            difficulty: <[u8; 32]>::from(header.difficulty).to_vec(),
+            nonce: header.seal.nonce as i64,
+            proof: header.seal.proof.to_vec(),
+            cellbase_id: header.cellbase_id.to_vec(),
+            uncles_hash: header.uncles_hash.to_vec(),
+        }
+    }
+}
 
-        let header = {
-            let parent_hash = fbb.create_vector(&self.header.parent_hash);
-            let txs_commit = fbb.create_vector(&self.header.txs_commit);
-            let txs_proposal = fbb.create_vector(&self.header.txs_proposal);
-            let difficulty = fbb.create_vector(&<[u8; 32]>::from(self.header.difficulty));
-            let proof = fbb.create_vector(&self.header.seal.proof);
-            let cellbase_id = fbb.create_vector(&self.header.cellbase_id);
-            let uncles_hash = fbb.create_vector(&self.header.uncles_hash);
+#[cfg(feature = "json")]
+impl From<JsonHeader> for Header {
+    fn from(header: JsonHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: header.parent_hash.0.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.0.into(),
+            txs_proposal: header.txs_proposal.0.into(),
+            difficulty: H256::from(header.difficulty.0).into(),
+            cellbase_id: header.cellbase_id.0.into(),
+            uncles_hash: header.uncles_hash.0.into(),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof.0,
+            },
+        }
+    }
+}
 
-            let mut builder = HeaderBuilder::new(fbb);
-            builder.add_version(self.header.version);
-            builder.add_parent_hash(parent_hash);
-            builder.add_timestamp(self.header.timestamp);
-            builder.add_number(self.header.number);
-            builder.add_txs_commit(txs_commit);
-            builder.add_txs_proposal(txs_proposal);
-            builder.add_difficulty(difficulty);
-            builder.add_nonce(self.header.seal.nonce);
-            builder.add_proof(proof);
-            builder.add_cellbase_id(cellbase_id);
-            builder.add_uncles_hash(uncles_hash);
-            builder.finish()
-        };
+#[cfg(feature = "json")]
+impl<'a> From<&'a Header> for JsonHeader {
+    fn from(header: &Header) -> Self {
+        JsonHeader {
+            version: header.version,
+            parent_hash: JsonByte32(header.parent_hash.into()),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: JsonByte32(header.txs_commit.into()),
+            txs_proposal: JsonByte32(header.txs_proposal.into()),
+            difficulty: JsonByte32(header.difficulty.into()),
+            nonce: header.seal.nonce,
+            proof: JsonBytes(header.seal.proof.to_vec()),
+            cellbase_id: JsonByte32(header.cellbase_id.into()),
+            uncles_hash: JsonByte32(header.uncles_hash.into()),
+        }
+    }
+}
 
-        let vec = self
-            .transactions
-            .iter()
-            .map(|transaction| {
-                let vec = transaction
-                    .deps
-                    .iter()
-                    .map(|out_point| {
-                        let hash = fbb.create_vector(&out_point.hash);
-                        let mut builder = OutPointBuilder::new(fbb);
-                        builder.add_hash(hash);
-                        builder.add_index(out_point.index);
-                        builder.finish()
-                    })
-                    .collect::<Vec<_>>();
-                let deps = fbb.create_vector(&vec);
+#[cfg(feature = "bcs")]
+impl From<BcsHeader> for Header {
+    fn from(header: BcsHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: H256::from(header.difficulty).into(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof,
+            },
+        }
+    }
+}
 
-                let vec = transaction
-                    .inputs
-                    .iter()
-                    .map(|input| {
-                        let hash = fbb.create_vector(&input.previous_output.hash);
-                        let unlock = fbb.create_vector(&input.unlock);
-                        let mut builder = CellInputBuilder::new(fbb);
-                        builder.add_hash(hash);
-                        builder.add_index(input.previous_output.index);
-                        builder.add_unlock(unlock);
-                        builder.finish()
-                    })
-                    .collect::<Vec<_>>();
-                let inputs = fbb.create_vector(&vec);
+#[cfg(feature = "bcs")]
+impl<'a> From<&'a Header> for BcsHeader {
+    fn from(header: &Header) -> Self {
+        BcsHeader {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: header.difficulty.into(),
+            nonce: header.seal.nonce,
+            proof: header.seal.proof.to_vec(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+        }
+    }
+}
 
-                let vec = transaction
-                    .outputs
-                    .iter()
-                    .map(|output| {
-                        let data = fbb.create_vector(&output.data);
-                        let lock = fbb.create_vector(&output.lock);
-                        let mut builder = CellOutputBuilder::new(fbb);
-                        builder.add_capacity(output.capacity);
-                        builder.add_data(data);
-                        builder.add_lock(lock);
-                        builder.finish()
-                    })
-                    .collect::<Vec<_>>();
-                let outputs = fbb.create_vector(&vec);
+#[cfg(feature = "postcard")]
+impl From<PostcardHeader> for Header {
+    fn from(header: PostcardHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: H256::from(header.difficulty).into(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof,
+            },
+        }
+    }
+}
 
-                let mut builder = TransactionBuilder::new(fbb);
-                builder.add_version(transaction.version);
-                builder.add_deps(deps);
-                builder.add_inputs(inputs);
-                builder.add_outputs(outputs);
-                builder.finish()
-            })
-            .collect::<Vec<_>>();
-
-        let transactions = fbb.create_vector(&vec);
-
-        let message = {
-            let mut builder = BlockBuilder::new(fbb);
-            builder.add_header(header);
-            builder.add_transactions(transactions);
-            builder.finish()
-        };
-        fbb.finish(message, None);
-        fbb.finished_data().to_vec()
+#[cfg(feature = "postcard")]
+impl From<PostcardHeaplessHeader> for Header {
+    fn from(header: PostcardHeaplessHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: H256::from(header.difficulty).into(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof.to_vec(),
+            },
+        }
     }
+}
 
-    pub fn from_flatbuffers(data: &[u8]) -> Self {
-        get_root::<FbsBlock>(data).borrow().into()
+#[cfg(feature = "postcard")]
+impl<'a> From<&'a Header> for PostcardHeader {
+    fn from(header: &Header) -> Self {
+        PostcardHeader {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: header.difficulty.into(),
+            nonce: header.seal.nonce,
+            proof: header.seal.proof.to_vec(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+        }
     }
+}
 
-    pub fn to_protobuf(&self) -> Vec<u8> {
-        let block: ProtobufBlock = self.into();
-        block.write_to_bytes().unwrap()
+#[cfg(feature = "der")]
+impl From<DerHeader> for Header {
+    fn from(header: DerHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: header.parent_hash.0.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.0.into(),
+            txs_proposal: header.txs_proposal.0.into(),
+            difficulty: H256::from(header.difficulty.0).into(),
+            cellbase_id: header.cellbase_id.0.into(),
+            uncles_hash: header.uncles_hash.0.into(),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof.into_bytes(),
+            },
+        }
     }
+}
 
-    pub fn from_protobuf(data: &[u8]) -> Self {
-        let block = parse_from_bytes::<ProtobufBlock>(data).unwrap();
-        block.borrow().into()
+#[cfg(feature = "der")]
+impl<'a> From<&'a Header> for DerHeader {
+    fn from(header: &Header) -> Self {
+        DerHeader {
+            version: header.version,
+            parent_hash: DerByte32(header.parent_hash.into()),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: DerByte32(header.txs_commit.into()),
+            txs_proposal: DerByte32(header.txs_proposal.into()),
+            difficulty: DerByte32(header.difficulty.into()),
+            nonce: header.seal.nonce,
+            proof: OctetString::new(header.seal.proof.to_vec()).unwrap(),
+            cellbase_id: DerByte32(header.cellbase_id.into()),
+            uncles_hash: DerByte32(header.uncles_hash.into()),
+        }
     }
+}
 
-    pub fn to_molecule(&self) -> Vec<u8> {
-        let header = MolHeader::new_builder()
-            .version(Uint32::from_slice(&self.header.version.to_le_bytes()).unwrap())
-            .parent_hash(Byte32::from_slice(&self.header.parent_hash).unwrap())
-            .timestamp(Uint64::from_slice(&self.header.timestamp.to_le_bytes()).unwrap())
-            .number(Uint64::from_slice(&self.header.number.to_le_bytes()).unwrap())
-            .txs_commit(Byte32::from_slice(&self.header.txs_commit).unwrap())
-            .txs_proposal(Byte32::from_slice(&self.header.txs_proposal).unwrap())
-            .difficulty(Byte32::from_slice(&H256::from(self.header.difficulty)).unwrap())
-            .nonce(Uint64::from_slice(&self.header.seal.nonce.to_le_bytes()).unwrap())
-            .proof(
-                MolBytes::new_builder()
-                    .extend(self.header.seal.proof.clone().into_iter().map(Into::into))
-                    .build(),
-            )
-            .cellbase_id(Byte32::from_slice(&self.header.cellbase_id).unwrap())
-            .uncles_hash(Byte32::from_slice(&self.header.uncles_hash).unwrap())
-            .build();
-
-        let transactions: Vec<_> = self
-            .transactions
-            .iter()
-            .map(|tx| {
-                let deps: Vec<_> = tx
-                    .deps
-                    .iter()
-                    .map(|out_point| {
-                        MolOutPoint::new_builder()
-                            .hash(Byte32::from_slice(&out_point.hash).unwrap())
-                            .index(Uint32::from_slice(&out_point.index.to_le_bytes()).unwrap())
-                            .build()
-                    })
-                    .collect();
-
-                let inputs: Vec<_> = tx
-                    .inputs
-                    .iter()
-                    .map(|input| {
-                        MolCellInput::new_builder()
-                            .hash(Byte32::from_slice(&input.previous_output.hash).unwrap())
-                            .index(
-                                Uint32::from_slice(&input.previous_output.index.to_le_bytes())
-                                    .unwrap(),
-                            )
-                            .unlock(
-                                MolBytes::new_builder()
-                                    .extend(input.unlock.clone().into_iter().map(Into::into))
-                                    .build(),
-                            )
-                            .build()
-                    })
-                    .collect();
-
-                let outputs: Vec<_> = tx
-                    .outputs
-                    .iter()
-                    .map(|output| {
-                        MolCellOutput::new_builder()
-                            .capacity(Uint64::from_slice(&output.capacity.to_le_bytes()).unwrap())
-                            .data(
-                                MolBytes::new_builder()
-                                    .extend(output.data.clone().into_iter().map(Into::into))
-                                    .build(),
-                            )
-                            .lock(Byte32::from_slice(&output.lock).unwrap())
-                            .build()
-                    })
-                    .collect();
-
-                MolTransaction::new_builder()
-                    .version(Uint32::from_slice(&tx.version.to_le_bytes()).unwrap())
-                    .deps(OutPointVec::new_builder().extend(deps).build())
-                    .inputs(CellInputVec::new_builder().extend(inputs).build())
-                    .outputs(CellOutputVec::new_builder().extend(outputs).build())
-                    .build()
-            })
-            .collect();
-
-        MolBlock::new_builder()
-            .header(header)
-            .transactions(TransactionVec::new_builder().extend(transactions).build())
-            .build()
-            .as_slice()
-            .into()
+#[cfg(feature = "xdr")]
+impl From<XdrHeader> for Header {
+    fn from(header: XdrHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: H256::from(header.difficulty).into(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof,
+            },
+        }
     }
+}
 
-    pub fn from_molecule(data: &[u8]) -> Self {
-        let block = MolBlockReader::from_slice(data).unwrap();
-        block.into()
+#[cfg(feature = "xdr")]
+impl<'a> From<&'a Header> for XdrHeader {
+    fn from(header: &Header) -> Self {
+        XdrHeader {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: header.difficulty.into(),
+            nonce: header.seal.nonce,
+            proof: header.seal.proof.to_vec(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+        }
     }
 }
 
-impl Transaction {
-    pub fn random(io_size: usize) -> Self {
-        Transaction {
-            version: thread_rng().gen_range(1, 10),
-            deps: (0..io_size).map(|_| OutPoint::random()).collect(),
-            inputs: (0..io_size).map(|_| CellInput::random()).collect(),
-            outputs: (0..io_size).map(|_| CellOutput::random()).collect(),
+#[cfg(feature = "flatbuffers")]
+impl<'a> From<&'a FbsStructHeader<'a>> for Header {
+    fn from(header: &FbsStructHeader<'a>) -> Self {
+        Header {
+            version: header.version(),
+            parent_hash: header.parent_hash().unwrap().into(),
+            timestamp: header.timestamp(),
+            number: header.number(),
+            txs_commit: header.txs_commit().unwrap().into(),
+            txs_proposal: header.txs_proposal().unwrap().into(),
+            difficulty: H256::from(header.difficulty().unwrap()).into(),
+            cellbase_id: header.cellbase_id().unwrap().into(),
+            uncles_hash: header.uncles_hash().unwrap().into(),
+            seal: Seal {
+                nonce: header.nonce(),
+                proof: header.proof().unwrap().to_vec(),
+            },
         }
     }
 }
 
-impl<'a> From<FbsTransaction<'a>> for Transaction {
-    fn from(transaction: FbsTransaction<'a>) -> Self {
-        let deps = FlatbuffersVectorIterator::new(transaction.deps().unwrap())
-            .map(Into::into)
-            .collect();
-
-        let inputs = FlatbuffersVectorIterator::new(transaction.inputs().unwrap())
-            .map(Into::into)
-            .collect();
-
-        let outputs = FlatbuffersVectorIterator::new(transaction.outputs().unwrap())
-            .map(Into::into)
-            .collect();
+#[cfg(feature = "flatbuffers")]
+impl<'a> From<&'a FbsStructByte32> for H256 {
+    fn from(byte32: &FbsStructByte32) -> Self {
+        H256::from(*byte32.bytes())
+    }
+}
 
-        Transaction {
-            version: transaction.version(),
-            deps,
-            inputs,
-            outputs,
-        }
+#[cfg(feature = "flatbuffers")]
+impl<'a> From<&'a H256> for FbsStructByte32 {
+    fn from(hash: &H256) -> Self {
+        FbsStructByte32::new(&(*hash).into())
     }
 }
 
-impl<'a> From<&'a ProtobufTransaction> for Transaction {
-    fn from(transaction: &ProtobufTransaction) -> Self {
-        Transaction {
-            version: transaction.get_version(),
-            deps: transaction.get_deps().iter().map(Into::into).collect(),
-            inputs: transaction.get_inputs().iter().map(Into::into).collect(),
-            outputs: transaction.get_outputs().iter().map(Into::into).collect(),
+#[cfg(feature = "molecule")]
+impl From<MolStructHeaderReader<'_>> for Header {
+    fn from(header: MolStructHeaderReader) -> Self {
+        let raw = header.raw();
+        Header {
+            version: u32::from_le_bytes(raw.version().as_slice().try_into().unwrap()),
+            parent_hash: H256::from_slice(raw.parent_hash().as_slice()),
+            timestamp: u64::from_le_bytes(raw.timestamp().as_slice().try_into().unwrap()),
+            number: u64::from_le_bytes(raw.number().as_slice().try_into().unwrap()),
+            txs_commit: H256::from_slice(raw.txs_commit().as_slice()),
+            txs_proposal: H256::from_slice(raw.txs_proposal().as_slice()),
+            difficulty: H256::from_slice(raw.difficulty().as_slice()).into(),
+            cellbase_id: H256::from_slice(raw.cellbase_id().as_slice()),
+            uncles_hash: H256::from_slice(raw.uncles_hash().as_slice()),
+            seal: Seal {
+                nonce: u64::from_le_bytes(header.nonce().as_slice().try_into().unwrap()),
+                proof: header.proof().raw_data().as_ref().into(),
+            },
         }
     }
 }
 
-impl<'a> From<&'a Transaction> for ProtobufTransaction {
-    fn from(transaction: &Transaction) -> Self {
-        let mut result = ProtobufTransaction::new();
-        result.set_version(transaction.version);
-        result.set_deps(transaction.deps.iter().map(Into::into).collect());
-        result.set_inputs(transaction.inputs.iter().map(Into::into).collect());
-        result.set_outputs(transaction.outputs.iter().map(Into::into).collect());
-        result
+#[cfg(feature = "prost")]
+impl From<ProstFixedHeader> for Header {
+    fn from(header: ProstFixedHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: H256::from_slice(&header.parent_hash),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: H256::from_slice(&header.txs_commit),
+            txs_proposal: H256::from_slice(&header.txs_proposal),
+            difficulty: H256::from_slice(&header.difficulty).into(),
+            cellbase_id: H256::from_slice(&header.cellbase_id),
+            uncles_hash: H256::from_slice(&header.uncles_hash),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof,
+            },
+        }
     }
 }
 
-impl From<MolTransactionReader<'_>> for Transaction {
-    fn from(transaction: MolTransactionReader) -> Self {
-        let deps = transaction.deps().iter().map(Into::into).collect();
-        let inputs = transaction.inputs().iter().map(Into::into).collect();
-        let outputs = transaction.outputs().iter().map(Into::into).collect();
-
-        Transaction {
-            version: u32::from_le_bytes(transaction.version().as_slice().try_into().unwrap()),
-            deps,
-            inputs,
-            outputs,
+#[cfg(feature = "prost")]
+impl<'a> From<&'a Header> for ProstFixedHeader {
+    fn from(header: &Header) -> Self {
+        ProstFixedHeader {
+            version: header.version,
+            parent_hash: header.parent_hash.to_vec(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.to_vec(),
+            txs_proposal: header.txs_proposal.to_vec(),
+            difficulty: <[u8; 32]>::from(header.difficulty).to_vec(),
+            nonce: header.seal.nonce,
+            proof: header.seal.proof.to_vec(),
+            cellbase_id: header.cellbase_id.to_vec(),
+            uncles_hash: header.uncles_hash.to_vec(),
         }
     }
 }
 
-impl OutPoint {
-    pub fn random() -> Self {
-        OutPoint {
-            hash: H256::random(),
-            index: thread_rng().gen_range(1, 10),
+#[cfg(feature = "flexbuffers")]
+impl From<FlexHeader> for Header {
+    fn from(header: FlexHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: H256::from(header.difficulty).into(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof,
+            },
         }
     }
 }
 
-impl<'a> From<FbsOutPoint<'a>> for OutPoint {
-    fn from(out_point: FbsOutPoint<'a>) -> Self {
-        OutPoint {
-            hash: H256::from_slice(out_point.hash().unwrap()),
-            index: out_point.index(),
+#[cfg(feature = "flexbuffers")]
+impl<'a> From<&'a Header> for FlexHeader {
+    fn from(header: &Header) -> Self {
+        FlexHeader {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: header.difficulty.into(),
+            nonce: header.seal.nonce,
+            proof: header.seal.proof.to_vec(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
         }
     }
 }
 
-impl<'a> From<&'a ProtobufOutPoint> for OutPoint {
-    fn from(out_point: &ProtobufOutPoint) -> Self {
-        OutPoint {
-            hash: H256::from_slice(out_point.get_hash()),
-            index: out_point.get_index(),
+#[cfg(feature = "bitcoin")]
+impl From<BtcHeader> for Header {
+    fn from(header: BtcHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: H256::from(header.difficulty).into(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof,
+            },
         }
     }
 }
 
-impl<'a> From<&'a OutPoint> for ProtobufOutPoint {
-    fn from(out_point: &OutPoint) -> Self {
-        let mut result = ProtobufOutPoint::new();
-        result.set_hash(out_point.hash.to_vec());
-        result.set_index(out_point.index);
-        result
+#[cfg(feature = "bitcoin")]
+impl<'a> From<&'a Header> for BtcHeader {
+    fn from(header: &Header) -> Self {
+        BtcHeader {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: header.difficulty.into(),
+            nonce: header.seal.nonce,
+            proof: header.seal.proof.to_vec(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+        }
     }
 }
 
-impl From<MolOutPointReader<'_>> for OutPoint {
-    fn from(out_point: MolOutPointReader) -> Self {
-        OutPoint {
-            hash: H256::from_slice(out_point.hash().as_slice()),
-            index: u32::from_le_bytes(out_point.index().as_slice().try_into().unwrap()),
+#[cfg(feature = "bitcode")]
+impl From<BitcodeHeader> for Header {
+    fn from(header: BitcodeHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: H256::from(header.difficulty).into(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof,
+            },
         }
     }
 }
 
-impl CellInput {
-    pub fn random() -> Self {
-        CellInput {
-            previous_output: OutPoint::random(),
-            unlock: thread_rng().sample_iter(&Standard).take(100).collect(),
+#[cfg(feature = "bitcode")]
+impl<'a> From<&'a Header> for BitcodeHeader {
+    fn from(header: &Header) -> Self {
+        BitcodeHeader {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: header.difficulty.into(),
+            nonce: header.seal.nonce,
+            proof: header.seal.proof.to_vec(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
         }
     }
 }
 
-impl<'a> From<FbsCellInput<'a>> for CellInput {
-    fn from(cell_input: FbsCellInput<'a>) -> Self {
-        CellInput {
-            previous_output: OutPoint {
-                hash: H256::from_slice(cell_input.hash().unwrap()),
-                index: cell_input.index(),
+#[cfg(feature = "bson")]
+impl From<BsonHeader> for Header {
+    fn from(header: BsonHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: H256::from(header.difficulty).into(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof,
             },
-            unlock: cell_input.unlock().unwrap().to_vec(),
         }
     }
 }
 
-impl<'a> From<&'a ProtobufCellInput> for CellInput {
-    fn from(cell_input: &ProtobufCellInput) -> Self {
-        CellInput {
-            previous_output: OutPoint {
-                hash: H256::from_slice(cell_input.get_hash()),
-                index: cell_input.get_index(),
-            },
-            unlock: cell_input.get_unlock().to_vec(),
+#[cfg(feature = "bson")]
+impl<'a> From<&'a Header> for BsonHeader {
+    fn from(header: &Header) -> Self {
+        BsonHeader {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: header.difficulty.into(),
+            nonce: header.seal.nonce,
+            proof: header.seal.proof.to_vec(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+        }
+    }
+}
+
+#[cfg(feature = "sbe")]
+impl From<SbeHeader> for Header {
+    fn from(header: SbeHeader) -> Self {
+        Header {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: H256::from(header.difficulty).into(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+            seal: Seal {
+                nonce: header.nonce,
+                proof: header.proof,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "sbe")]
+impl<'a> From<&'a Header> for SbeHeader {
+    fn from(header: &Header) -> Self {
+        SbeHeader {
+            version: header.version,
+            parent_hash: header.parent_hash.into(),
+            timestamp: header.timestamp,
+            number: header.number,
+            txs_commit: header.txs_commit.into(),
+            txs_proposal: header.txs_proposal.into(),
+            difficulty: header.difficulty.into(),
+            nonce: header.seal.nonce,
+            proof: header.seal.proof.to_vec(),
+            cellbase_id: header.cellbase_id.into(),
+            uncles_hash: header.uncles_hash.into(),
+        }
+    }
+}
+
+impl Header {
+    pub fn random() -> Self {
+        Header {
+            version: thread_rng().gen_range(1, 10),
+            parent_hash: H256::random(),
+            timestamp: thread_rng().gen_range(1500000000, 1600000000),
+            number: thread_rng().gen_range(0, 100000),
+            txs_commit: H256::random(),
+            txs_proposal: H256::random(),
+            difficulty: H256::random().into(),
+            cellbase_id: H256::random(),
+            uncles_hash: H256::random(),
+            seal: Seal {
+                nonce: thread_rng().gen_range(0, 100000),
+                proof: H256::random().to_vec(),
+            },
+        }
+    }
+
+    #[cfg(feature = "flatbuffers")]
+    pub fn to_flatbuffers(&self) -> Vec<u8> {
+        let fbb = &mut FlatBufferBuilder::new();
+        let parent_hash = fbb.create_vector(&self.parent_hash);
+        let txs_commit = fbb.create_vector(&self.txs_commit);
+        let txs_proposal = fbb.create_vector(&self.txs_proposal);
+        let difficulty = fbb.create_vector(&<[u8; 32]>::from(self.difficulty));
+        let proof = fbb.create_vector(&self.seal.proof);
+        let cellbase_id = fbb.create_vector(&self.cellbase_id);
+        let uncles_hash = fbb.create_vector(&self.uncles_hash);
+
+        let message = {
+            let mut builder = HeaderBuilder::new(fbb);
+            builder.add_version(self.version);
+            builder.add_parent_hash(parent_hash);
+            builder.add_timestamp(self.timestamp);
+            builder.add_number(self.number);
+            builder.add_txs_commit(txs_commit);
+            builder.add_txs_proposal(txs_proposal);
+            builder.add_difficulty(difficulty);
+            builder.add_nonce(self.seal.nonce);
+            builder.add_proof(proof);
+            builder.add_cellbase_id(cellbase_id);
+            builder.add_uncles_hash(uncles_hash);
+            builder.finish()
+        };
+        fbb.finish(message, None);
+        fbb.finished_data().to_vec()
+    }
+
+    #[cfg(feature = "flatbuffers")]
+    pub fn from_flatbuffers(data: &[u8]) -> Self {
+        (&get_root::<FbsHeader>(data)).into()
+    }
+
+    #[cfg(feature = "protobuf")]
+    pub fn to_protobuf(&self) -> Vec<u8> {
+        let header: ProtobufHeader = self.into();
+        header.write_to_bytes().unwrap()
+    }
+
+    #[cfg(feature = "protobuf")]
+    pub fn from_protobuf(data: &[u8]) -> Self {
+        let header = ProtobufHeader::parse_from_bytes(data).unwrap();
+        (&header).into()
+    }
+
+    #[cfg(feature = "molecule")]
+    pub fn to_molecule(&self) -> Vec<u8> {
+        MolHeader::new_builder()
+            .version(Uint32::from_slice(&self.version.to_le_bytes()).unwrap())
+            .parent_hash(Byte32::from_slice(&self.parent_hash).unwrap())
+            .timestamp(Uint64::from_slice(&self.timestamp.to_le_bytes()).unwrap())
+            .number(Uint64::from_slice(&self.number.to_le_bytes()).unwrap())
+            .txs_commit(Byte32::from_slice(&self.txs_commit).unwrap())
+            .txs_proposal(Byte32::from_slice(&self.txs_proposal).unwrap())
+            .difficulty(Byte32::from_slice(&H256::from(self.difficulty)).unwrap())
+            .nonce(Uint64::from_slice(&self.seal.nonce.to_le_bytes()).unwrap())
+            .proof(
+                MolBytes::new_builder()
+                    .extend(self.seal.proof.clone().into_iter().map(Into::into))
+                    .build(),
+            )
+            .cellbase_id(Byte32::from_slice(&self.cellbase_id).unwrap())
+            .uncles_hash(Byte32::from_slice(&self.uncles_hash).unwrap())
+            .build()
+            .as_slice()
+            .into()
+    }
+
+    #[cfg(feature = "molecule")]
+    pub fn from_molecule(data: &[u8]) -> Self {
+        let header = MolHeaderReader::from_slice(data).unwrap();
+        header.into()
+    }
+
+    #[cfg(feature = "scale")]
+    pub fn to_scale(&self) -> Vec<u8> {
+        let header: ScaleHeader = self.into();
+        header.encode()
+    }
+
+    #[cfg(feature = "scale")]
+    pub fn from_scale(data: &[u8]) -> Self {
+        let header = ScaleHeader::decode(&mut &data[..]).unwrap();
+        header.into()
+    }
+
+    #[cfg(feature = "borsh")]
+    pub fn to_borsh(&self) -> Vec<u8> {
+        let header: BorshHeader = self.into();
+        borsh::to_vec(&header).unwrap()
+    }
+
+    #[cfg(feature = "borsh")]
+    pub fn from_borsh(data: &[u8]) -> Self {
+        let header = BorshHeader::try_from_slice(data).unwrap();
+        header.into()
+    }
+
+    #[cfg(feature = "ssz")]
+    pub fn to_ssz(&self) -> Vec<u8> {
+        let header: SszHeader = self.into();
+        header.check_limits().unwrap();
+        header.as_ssz_bytes()
+    }
+
+    #[cfg(feature = "ssz")]
+    pub fn from_ssz(data: &[u8]) -> Self {
+        let header = SszHeader::from_ssz_bytes(data).unwrap();
+        header.check_limits().unwrap();
+        header.into()
+    }
+
+    #[cfg(feature = "ssz")]
+    pub fn hash_tree_root(&self) -> H256 {
+        let header: SszHeader = self.into();
+        header.hash_tree_root().into()
+    }
+
+    /// `to_ssz` and `hash_tree_root` sharing a single conversion to `SszHeader`.
+    #[cfg(feature = "ssz")]
+    pub fn to_ssz_with_root(&self) -> (Vec<u8>, H256) {
+        let header: SszHeader = self.into();
+        header.check_limits().unwrap();
+        (header.as_ssz_bytes(), header.hash_tree_root().into())
+    }
+
+    #[cfg(feature = "rkyv")]
+    pub fn to_rkyv(&self) -> AlignedVec {
+        rkyv::to_bytes::<_, 256>(self).unwrap()
+    }
+
+    #[cfg(feature = "rkyv")]
+    pub fn from_rkyv(data: &[u8]) -> Self {
+        let header = rkyv::check_archived_root::<Header>(data).unwrap();
+        header.deserialize(&mut Infallible).unwrap()
+    }
+
+    /// # Safety
+    ///
+    /// `data` is accessed without validation, it must be the output of `to_rkyv`.
+    #[cfg(feature = "rkyv")]
+    pub unsafe fn from_rkyv_unchecked(data: &[u8]) -> Self {
+        let header = rkyv::archived_root::<Header>(data);
+        header.deserialize(&mut Infallible).unwrap()
+    }
+
+    #[cfg(feature = "prost")]
+    pub fn to_prost(&self) -> Vec<u8> {
+        let header: ProstHeader = self.into();
+        header.encode_to_vec()
+    }
+
+    #[cfg(feature = "prost")]
+    pub fn from_prost(data: &[u8]) -> Self {
+        let header = ProstHeader::decode(data).unwrap();
+        header.into()
+    }
+
+    #[cfg(feature = "quick-protobuf")]
+    pub fn to_quick_protobuf(&self) -> Vec<u8> {
+        let header: QuickHeader = self.into();
+        let mut data = Vec::with_capacity(header.get_size());
+        header.write_message(&mut Writer::new(&mut data)).unwrap();
+        data
+    }
+
+    #[cfg(feature = "quick-protobuf")]
+    pub fn from_quick_protobuf(data: &[u8]) -> Self {
+        let header = QuickHeader::from_reader(&mut BytesReader::from_bytes(data), data).unwrap();
+        header.into()
+    }
+
+    #[cfg(feature = "avro")]
+    pub fn to_avro(&self) -> Vec<u8> {
+        to_avro_datum(&avro_schemas().header, AvroValue::from(self)).unwrap()
+    }
+
+    #[cfg(feature = "avro")]
+    pub fn from_avro(data: &[u8]) -> Self {
+        let mut reader = data;
+        from_avro_datum(&avro_schemas().header, &mut reader, None)
+            .unwrap()
+            .into()
+    }
+
+    #[cfg(feature = "thrift")]
+    pub fn to_thrift_binary(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        let mut protocol = TBinaryOutputProtocol::new(&mut data, true);
+        ThriftHeader::from(self)
+            .write_to_out_protocol(&mut protocol)
+            .unwrap();
+        data
+    }
+
+    #[cfg(feature = "thrift")]
+    pub fn from_thrift_binary(data: &[u8]) -> Self {
+        let mut protocol = TBinaryInputProtocol::new(data, true);
+        ThriftHeader::read_from_in_protocol(&mut protocol)
+            .unwrap()
+            .into()
+    }
+
+    #[cfg(feature = "thrift")]
+    pub fn to_thrift_compact(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        let mut protocol = TCompactOutputProtocol::new(&mut data);
+        ThriftHeader::from(self)
+            .write_to_out_protocol(&mut protocol)
+            .unwrap();
+        data
+    }
+
+    #[cfg(feature = "thrift")]
+    pub fn from_thrift_compact(data: &[u8]) -> Self {
+        let mut protocol = TCompactInputProtocol::new(data);
+        ThriftHeader::read_from_in_protocol(&mut protocol)
+            .unwrap()
+            .into()
+    }
+
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> Vec<u8> {
+        serde_json::to_vec(&JsonHeader::from(self)).unwrap()
+    }
+
+    #[cfg(feature = "json")]
+    pub fn from_json(data: &[u8]) -> Self {
+        serde_json::from_slice::<JsonHeader>(data).unwrap().into()
+    }
+
+    /// simd-json parses in place, so the input is copied into a mutable buffer
+    /// first.
+    #[cfg(feature = "json")]
+    pub fn from_json_simd(data: &[u8]) -> Self {
+        let mut data = data.to_vec();
+        simd_json::serde::from_slice::<JsonHeader>(&mut data)
+            .unwrap()
+            .into()
+    }
+
+    #[cfg(feature = "bcs")]
+    pub fn to_bcs(&self) -> Vec<u8> {
+        let header: BcsHeader = self.into();
+        bcs::to_bytes(&header).unwrap()
+    }
+
+    #[cfg(feature = "bcs")]
+    pub fn from_bcs(data: &[u8]) -> Self {
+        let header: BcsHeader = bcs::from_bytes(data).unwrap();
+        header.into()
+    }
+
+    #[cfg(feature = "postcard")]
+    pub fn to_postcard(&self) -> Vec<u8> {
+        let header: PostcardHeader = self.into();
+        postcard::to_allocvec(&header).unwrap()
+    }
+
+    #[cfg(feature = "postcard")]
+    pub fn from_postcard(data: &[u8]) -> Self {
+        let header: PostcardHeader = postcard::from_bytes(data).unwrap();
+        header.into()
+    }
+
+    /// Decodes `to_postcard` output into the fixed-capacity header without
+    /// allocating. Panics if `proof` is longer than `MAX_PROOF_LENGTH`.
+    #[cfg(feature = "postcard")]
+    pub fn from_postcard_heapless(data: &[u8]) -> PostcardHeaplessHeader {
+        PostcardHeaplessHeader::from_postcard(data).unwrap()
+    }
+
+    #[cfg(feature = "der")]
+    pub fn to_der(&self) -> Vec<u8> {
+        DerHeader::from(self).to_der().unwrap()
+    }
+
+    #[cfg(feature = "der")]
+    pub fn from_der(data: &[u8]) -> Self {
+        DerHeader::from_der(data).unwrap().into()
+    }
+
+    #[cfg(feature = "xdr")]
+    pub fn to_xdr(&self) -> Vec<u8> {
+        bench_xdr::to_bytes(&XdrHeader::from(self))
+    }
+
+    #[cfg(feature = "xdr")]
+    pub fn from_xdr(data: &[u8]) -> Self {
+        bench_xdr::from_bytes::<XdrHeader>(data).unwrap().into()
+    }
+
+    #[cfg(feature = "raw")]
+    pub fn to_raw(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        self.write_raw(&mut data);
+        data
+    }
+
+    #[cfg(feature = "raw")]
+    pub fn from_raw(data: &[u8]) -> Self {
+        bench_raw::from_bytes(data).unwrap()
+    }
+
+    #[cfg(feature = "flatbuffers")]
+    pub fn to_flatbuffers_struct(&self) -> Vec<u8> {
+        let fbb = &mut FlatBufferBuilder::new();
+        let parent_hash = (&self.parent_hash).into();
+        let txs_commit = (&self.txs_commit).into();
+        let txs_proposal = (&self.txs_proposal).into();
+        let difficulty = FbsStructByte32::new(&self.difficulty.into());
+        let proof = fbb.create_vector(&self.seal.proof);
+        let cellbase_id = (&self.cellbase_id).into();
+        let uncles_hash = (&self.uncles_hash).into();
+
+        let message = {
+            let mut builder = FbsStructHeaderBuilder::new(fbb);
+            builder.add_version(self.version);
+            builder.add_parent_hash(&parent_hash);
+            builder.add_timestamp(self.timestamp);
+            builder.add_number(self.number);
+            builder.add_txs_commit(&txs_commit);
+            builder.add_txs_proposal(&txs_proposal);
+            builder.add_difficulty(&difficulty);
+            builder.add_nonce(self.seal.nonce);
+            builder.add_proof(proof);
+            builder.add_cellbase_id(&cellbase_id);
+            builder.add_uncles_hash(&uncles_hash);
+            builder.finish()
+        };
+        fbb.finish(message, None);
+        fbb.finished_data().to_vec()
+    }
+
+    #[cfg(feature = "flatbuffers")]
+    pub fn from_flatbuffers_struct(data: &[u8]) -> Self {
+        (&get_root::<FbsStructHeader>(data)).into()
+    }
+
+    #[cfg(feature = "molecule")]
+    pub fn to_molecule_struct(&self) -> Vec<u8> {
+        let raw = MolStructRawHeader::new_builder()
+            .version(MolStructUint32::from_slice(&self.version.to_le_bytes()).unwrap())
+            .parent_hash(MolStructByte32::from_slice(&self.parent_hash).unwrap())
+            .timestamp(MolStructUint64::from_slice(&self.timestamp.to_le_bytes()).unwrap())
+            .number(MolStructUint64::from_slice(&self.number.to_le_bytes()).unwrap())
+            .txs_commit(MolStructByte32::from_slice(&self.txs_commit).unwrap())
+            .txs_proposal(MolStructByte32::from_slice(&self.txs_proposal).unwrap())
+            .difficulty(MolStructByte32::from_slice(&H256::from(self.difficulty)).unwrap())
+            .cellbase_id(MolStructByte32::from_slice(&self.cellbase_id).unwrap())
+            .uncles_hash(MolStructByte32::from_slice(&self.uncles_hash).unwrap())
+            .build();
+
+        MolStructHeader::new_builder()
+            .raw(raw)
+            .nonce(MolStructUint64::from_slice(&self.seal.nonce.to_le_bytes()).unwrap())
+            .proof(
+                MolStructBytes::new_builder()
+                    .extend(self.seal.proof.clone().into_iter().map(Into::into))
+                    .build(),
+            )
+            .build()
+            .as_slice()
+            .into()
+    }
+
+    #[cfg(feature = "molecule")]
+    pub fn from_molecule_struct(data: &[u8]) -> Self {
+        let header = MolStructHeaderReader::from_slice(data).unwrap();
+        header.into()
+    }
+
+    #[cfg(feature = "prost")]
+    pub fn to_prost_fixed(&self) -> Vec<u8> {
+        let header: ProstFixedHeader = self.into();
+        header.encode_to_vec()
+    }
+
+    #[cfg(feature = "prost")]
+    pub fn from_prost_fixed(data: &[u8]) -> Self {
+        let header = ProstFixedHeader::decode(data).unwrap();
+        header.into()
+    }
+
+    #[cfg(feature = "flexbuffers")]
+    pub fn to_flexbuffers(&self) -> Vec<u8> {
+        let header: FlexHeader = self.into();
+        flexbuffers::to_vec(&header).unwrap()
+    }
+
+    #[cfg(feature = "flexbuffers")]
+    pub fn from_flexbuffers(data: &[u8]) -> Self {
+        let header: FlexHeader = flexbuffers::from_slice(data).unwrap();
+        header.into()
+    }
+
+    #[cfg(feature = "bitcoin")]
+    pub fn to_bitcoin(&self) -> Vec<u8> {
+        bench_bitcoin::to_bytes(&BtcHeader::from(self))
+    }
+
+    #[cfg(feature = "bitcoin")]
+    pub fn from_bitcoin(data: &[u8]) -> Self {
+        bench_bitcoin::from_bytes::<BtcHeader>(data).unwrap().into()
+    }
+
+    #[cfg(feature = "bitcode")]
+    pub fn to_bitcode(&self) -> Vec<u8> {
+        bitcode::encode(&BitcodeHeader::from(self))
+    }
+
+    #[cfg(feature = "bitcode")]
+    pub fn from_bitcode(data: &[u8]) -> Self {
+        bitcode::decode::<BitcodeHeader>(data).unwrap().into()
+    }
+
+    #[cfg(feature = "bson")]
+    pub fn to_bson(&self) -> Vec<u8> {
+        bson::to_vec(&BsonHeader::from(self)).unwrap()
+    }
+
+    #[cfg(feature = "bson")]
+    pub fn from_bson(data: &[u8]) -> Self {
+        bson::from_slice::<BsonHeader>(data).unwrap().into()
+    }
+
+    /// Panics if `proof` is longer than `bench_sbe::MAX_VAR_DATA_LENGTH`.
+    #[cfg(feature = "sbe")]
+    pub fn to_sbe(&self) -> Vec<u8> {
+        bench_sbe::to_bytes(&SbeHeader::from(self)).unwrap()
+    }
+
+    #[cfg(feature = "sbe")]
+    pub fn from_sbe(data: &[u8]) -> Self {
+        bench_sbe::from_bytes::<SbeHeader>(data).unwrap().into()
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> From<&'a FbsBlock<'a>> for Block {
+    fn from(block: &FbsBlock<'a>) -> Self {
+        Block {
+            header: (&block.header().unwrap()).into(),
+            transactions: FlatbuffersVectorIterator::new(block.transactions().unwrap())
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl<'a> From<&'a Block> for ProtobufBlock {
+    fn from(block: &Block) -> Self {
+        let mut result = ProtobufBlock::new();
+        result.set_header((&block.header).into());
+        result.set_transactions(block.transactions.iter().map(Into::into).collect());
+        result
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl<'a> From<&'a ProtobufBlock> for Block {
+    fn from(block: &ProtobufBlock) -> Self {
+        Block {
+            header: block.get_header().into(),
+            transactions: block.get_transactions().iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "molecule")]
+impl From<MolBlockReader<'_>> for Block {
+    fn from(block: MolBlockReader) -> Self {
+        Block {
+            header: block.header().into(),
+            transactions: block.transactions().iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "scale")]
+impl From<ScaleBlock> for Block {
+    fn from(block: ScaleBlock) -> Self {
+        Block {
+            header: block.header.into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "scale")]
+impl<'a> From<&'a Block> for ScaleBlock {
+    fn from(block: &Block) -> Self {
+        ScaleBlock {
+            header: (&block.header).into(),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl From<BorshBlock> for Block {
+    fn from(block: BorshBlock) -> Self {
+        Block {
+            header: block.header.into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<'a> From<&'a Block> for BorshBlock {
+    fn from(block: &Block) -> Self {
+        BorshBlock {
+            header: (&block.header).into(),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<SszBlock> for Block {
+    fn from(block: SszBlock) -> Self {
+        Block {
+            header: block.header.into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl<'a> From<&'a Block> for SszBlock {
+    fn from(block: &Block) -> Self {
+        SszBlock {
+            header: (&block.header).into(),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<ProstBlock> for Block {
+    fn from(block: ProstBlock) -> Self {
+        Block {
+            header: block.header.unwrap_or_default().into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl<'a> From<&'a Block> for ProstBlock {
+    fn from(block: &Block) -> Self {
+        ProstBlock {
+            header: Some((&block.header).into()),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl From<QuickBlock<'_>> for Block {
+    fn from(block: QuickBlock) -> Self {
+        Block {
+            header: block.header.unwrap_or_default().into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl<'a> From<&'a Block> for QuickBlock<'a> {
+    fn from(block: &'a Block) -> Self {
+        QuickBlock {
+            header: Some((&block.header).into()),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "avro")]
+impl From<AvroValue> for Block {
+    fn from(value: AvroValue) -> Self {
+        let mut fields = bench_avro::record(value);
+        let header = fields.take("header").into();
+        let transactions = bench_avro::array(fields.take("transactions"))
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Block {
+            header,
+            transactions,
+        }
+    }
+}
+
+#[cfg(feature = "avro")]
+impl<'a> From<&'a Block> for AvroValue {
+    fn from(block: &Block) -> Self {
+        AvroValue::Record(vec![
+            ("header".into(), (&block.header).into()),
+            (
+                "transactions".into(),
+                AvroValue::Array(block.transactions.iter().map(Into::into).collect()),
+            ),
+        ])
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<ThriftBlock> for Block {
+    fn from(block: ThriftBlock) -> Self {
+        Block {
+            header: block.header.into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl<'a> From<&'a Block> for ThriftBlock {
+    fn from(block: &Block) -> Self {
+        ThriftBlock {
+            header: (&block.header).into(),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<JsonBlock> for Block {
+    fn from(block: JsonBlock) -> Self {
+        Block {
+            header: block.header.into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'a> From<&'a Block> for JsonBlock {
+    fn from(block: &Block) -> Self {
+        JsonBlock {
+            header: (&block.header).into(),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bcs")]
+impl From<BcsBlock> for Block {
+    fn from(block: BcsBlock) -> Self {
+        Block {
+            header: block.header.into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bcs")]
+impl<'a> From<&'a Block> for BcsBlock {
+    fn from(block: &Block) -> Self {
+        BcsBlock {
+            header: (&block.header).into(),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl From<PostcardBlock> for Block {
+    fn from(block: PostcardBlock) -> Self {
+        Block {
+            header: block.header.into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl<'a> From<&'a Block> for PostcardBlock {
+    fn from(block: &Block) -> Self {
+        PostcardBlock {
+            header: (&block.header).into(),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "der")]
+impl From<DerBlock> for Block {
+    fn from(block: DerBlock) -> Self {
+        Block {
+            header: block.header.into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "der")]
+impl<'a> From<&'a Block> for DerBlock {
+    fn from(block: &Block) -> Self {
+        DerBlock {
+            header: (&block.header).into(),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "xdr")]
+impl From<XdrBlock> for Block {
+    fn from(block: XdrBlock) -> Self {
+        Block {
+            header: block.header.into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "xdr")]
+impl<'a> From<&'a Block> for XdrBlock {
+    fn from(block: &Block) -> Self {
+        XdrBlock {
+            header: (&block.header).into(),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> From<&'a FbsStructBlock<'a>> for Block {
+    fn from(block: &FbsStructBlock<'a>) -> Self {
+        Block {
+            header: (&block.header().unwrap()).into(),
+            transactions: FlatbuffersVectorIterator::new(block.transactions().unwrap())
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "molecule")]
+impl From<MolStructBlockReader<'_>> for Block {
+    fn from(block: MolStructBlockReader) -> Self {
+        Block {
+            header: block.header().into(),
+            transactions: block.transactions().iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<ProstFixedBlock> for Block {
+    fn from(block: ProstFixedBlock) -> Self {
+        Block {
+            header: block.header.unwrap_or_default().into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl<'a> From<&'a Block> for ProstFixedBlock {
+    fn from(block: &Block) -> Self {
+        ProstFixedBlock {
+            header: Some((&block.header).into()),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "flexbuffers")]
+impl From<FlexBlock> for Block {
+    fn from(block: FlexBlock) -> Self {
+        Block {
+            header: block.header.into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "flexbuffers")]
+impl<'a> From<&'a Block> for FlexBlock {
+    fn from(block: &Block) -> Self {
+        FlexBlock {
+            header: (&block.header).into(),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bitcoin")]
+impl From<BtcBlock> for Block {
+    fn from(block: BtcBlock) -> Self {
+        Block {
+            header: block.header.into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bitcoin")]
+impl<'a> From<&'a Block> for BtcBlock {
+    fn from(block: &Block) -> Self {
+        BtcBlock {
+            header: (&block.header).into(),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bitcode")]
+impl From<BitcodeBlock> for Block {
+    fn from(block: BitcodeBlock) -> Self {
+        Block {
+            header: block.header.into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bitcode")]
+impl<'a> From<&'a Block> for BitcodeBlock {
+    fn from(block: &Block) -> Self {
+        BitcodeBlock {
+            header: (&block.header).into(),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bson")]
+impl From<BsonBlock> for Block {
+    fn from(block: BsonBlock) -> Self {
+        Block {
+            header: block.header.into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bson")]
+impl<'a> From<&'a Block> for BsonBlock {
+    fn from(block: &Block) -> Self {
+        BsonBlock {
+            header: (&block.header).into(),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "sbe")]
+impl From<SbeBlock> for Block {
+    fn from(block: SbeBlock) -> Self {
+        Block {
+            header: block.header.into(),
+            transactions: block.transactions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "sbe")]
+impl<'a> From<&'a Block> for SbeBlock {
+    fn from(block: &Block) -> Self {
+        SbeBlock {
+            header: (&block.header).into(),
+            transactions: block.transactions.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Block {
+    pub fn random(transactions_size: usize, io_size: usize) -> Self {
+        Block {
+            header: Header::random(),
+            transactions: (0..transactions_size)
+                .map(|_| Transaction::random(io_size))
+                .collect(),
+        }
+    }
+
+    #[cfg(feature = "flatbuffers")]
+    pub fn to_flatbuffers(&self) -> Vec<u8> {
+        let fbb = &mut FlatBufferBuilder::new();
+
+        let header = {
+            let parent_hash = fbb.create_vector(&self.header.parent_hash);
+            let txs_commit = fbb.create_vector(&self.header.txs_commit);
+            let txs_proposal = fbb.create_vector(&self.header.txs_proposal);
+            let difficulty = fbb.create_vector(&<[u8; 32]>::from(self.header.difficulty));
+            let proof = fbb.create_vector(&self.header.seal.proof);
+            let cellbase_id = fbb.create_vector(&self.header.cellbase_id);
+            let uncles_hash = fbb.create_vector(&self.header.uncles_hash);
+
+            let mut builder = HeaderBuilder::new(fbb);
+            builder.add_version(self.header.version);
+            builder.add_parent_hash(parent_hash);
+            builder.add_timestamp(self.header.timestamp);
+            builder.add_number(self.header.number);
+            builder.add_txs_commit(txs_commit);
+            builder.add_txs_proposal(txs_proposal);
+            builder.add_difficulty(difficulty);
+            builder.add_nonce(self.header.seal.nonce);
+            builder.add_proof(proof);
+            builder.add_cellbase_id(cellbase_id);
+            builder.add_uncles_hash(uncles_hash);
+            builder.finish()
+        };
+
+        let vec = self
+            .transactions
+            .iter()
+            .map(|transaction| {
+                let vec = transaction
+                    .deps
+                    .iter()
+                    .map(|out_point| {
+                        let hash = fbb.create_vector(&out_point.hash);
+                        let mut builder = OutPointBuilder::new(fbb);
+                        builder.add_hash(hash);
+                        builder.add_index(out_point.index);
+                        builder.finish()
+                    })
+                    .collect::<Vec<_>>();
+                let deps = fbb.create_vector(&vec);
+
+                let vec = transaction
+                    .inputs
+                    .iter()
+                    .map(|input| {
+                        let hash = fbb.create_vector(&input.previous_output.hash);
+                        let unlock = fbb.create_vector(&input.unlock);
+                        let mut builder = CellInputBuilder::new(fbb);
+                        builder.add_hash(hash);
+                        builder.add_index(input.previous_output.index);
+                        builder.add_unlock(unlock);
+                        builder.finish()
+                    })
+                    .collect::<Vec<_>>();
+                let inputs = fbb.create_vector(&vec);
+
+                let vec = transaction
+                    .outputs
+                    .iter()
+                    .map(|output| {
+                        let data = fbb.create_vector(&output.data);
+                        let lock = fbb.create_vector(&output.lock);
+                        let mut builder = CellOutputBuilder::new(fbb);
+                        builder.add_capacity(output.capacity);
+                        builder.add_data(data);
+                        builder.add_lock(lock);
+                        builder.finish()
+                    })
+                    .collect::<Vec<_>>();
+                let outputs = fbb.create_vector(&vec);
+
+                let mut builder = TransactionBuilder::new(fbb);
+                builder.add_version(transaction.version);
+                builder.add_deps(deps);
+                builder.add_inputs(inputs);
+                builder.add_outputs(outputs);
+                builder.finish()
+            })
+            .collect::<Vec<_>>();
+
+        let transactions = fbb.create_vector(&vec);
+
+        let message = {
+            let mut builder = BlockBuilder::new(fbb);
+            builder.add_header(header);
+            builder.add_transactions(transactions);
+            builder.finish()
+        };
+        fbb.finish(message, None);
+        fbb.finished_data().to_vec()
+    }
+
+    #[cfg(feature = "flatbuffers")]
+    pub fn from_flatbuffers(data: &[u8]) -> Self {
+        (&get_root::<FbsBlock>(data)).into()
+    }
+
+    #[cfg(feature = "protobuf")]
+    pub fn to_protobuf(&self) -> Vec<u8> {
+        let block: ProtobufBlock = self.into();
+        block.write_to_bytes().unwrap()
+    }
+
+    #[cfg(feature = "protobuf")]
+    pub fn from_protobuf(data: &[u8]) -> Self {
+        let block = ProtobufBlock::parse_from_bytes(data).unwrap();
+        (&block).into()
+    }
+
+    #[cfg(feature = "molecule")]
+    pub fn to_molecule(&self) -> Vec<u8> {
+        let header = MolHeader::new_builder()
+            .version(Uint32::from_slice(&self.header.version.to_le_bytes()).unwrap())
+            .parent_hash(Byte32::from_slice(&self.header.parent_hash).unwrap())
+            .timestamp(Uint64::from_slice(&self.header.timestamp.to_le_bytes()).unwrap())
+            .number(Uint64::from_slice(&self.header.number.to_le_bytes()).unwrap())
+            .txs_commit(Byte32::from_slice(&self.header.txs_commit).unwrap())
+            .txs_proposal(Byte32::from_slice(&self.header.txs_proposal).unwrap())
+            .difficulty(Byte32::from_slice(&H256::from(self.header.difficulty)).unwrap())
+            .nonce(Uint64::from_slice(&self.header.seal.nonce.to_le_bytes()).unwrap())
+            .proof(
+                MolBytes::new_builder()
+                    .extend(self.header.seal.proof.clone().into_iter().map(Into::into))
+                    .build(),
+            )
+            .cellbase_id(Byte32::from_slice(&self.header.cellbase_id).unwrap())
+            .uncles_hash(Byte32::from_slice(&self.header.uncles_hash).unwrap())
+            .build();
+
+        let transactions: Vec<_> = self
+            .transactions
+            .iter()
+            .map(|tx| {
+                let deps: Vec<_> = tx
+                    .deps
+                    .iter()
+                    .map(|out_point| {
+                        MolOutPoint::new_builder()
+                            .hash(Byte32::from_slice(&out_point.hash).unwrap())
+                            .index(Uint32::from_slice(&out_point.index.to_le_bytes()).unwrap())
+                            .build()
+                    })
+                    .collect();
+
+                let inputs: Vec<_> = tx
+                    .inputs
+                    .iter()
+                    .map(|input| {
+                        MolCellInput::new_builder()
+                            .hash(Byte32::from_slice(&input.previous_output.hash).unwrap())
+                            .index(
+                                Uint32::from_slice(&input.previous_output.index.to_le_bytes())
+                                    .unwrap(),
+                            )
+                            .unlock(
+                                MolBytes::new_builder()
+                                    .extend(input.unlock.clone().into_iter().map(Into::into))
+                                    .build(),
+                            )
+                            .build()
+                    })
+                    .collect();
+
+                let outputs: Vec<_> = tx
+                    .outputs
+                    .iter()
+                    .map(|output| {
+                        MolCellOutput::new_builder()
+                            .capacity(Uint64::from_slice(&output.capacity.to_le_bytes()).unwrap())
+                            .data(
+                                MolBytes::new_builder()
+                                    .extend(output.data.clone().into_iter().map(Into::into))
+                                    .build(),
+                            )
+                            .lock(Byte32::from_slice(&output.lock).unwrap())
+                            .build()
+                    })
+                    .collect();
+
+                MolTransaction::new_builder()
+                    .version(Uint32::from_slice(&tx.version.to_le_bytes()).unwrap())
+                    .deps(OutPointVec::new_builder().extend(deps).build())
+                    .inputs(CellInputVec::new_builder().extend(inputs).build())
+                    .outputs(CellOutputVec::new_builder().extend(outputs).build())
+                    .build()
+            })
+            .collect();
+
+        MolBlock::new_builder()
+            .header(header)
+            .transactions(TransactionVec::new_builder().extend(transactions).build())
+            .build()
+            .as_slice()
+            .into()
+    }
+
+    #[cfg(feature = "molecule")]
+    pub fn from_molecule(data: &[u8]) -> Self {
+        let block = MolBlockReader::from_slice(data).unwrap();
+        block.into()
+    }
+
+    #[cfg(feature = "scale")]
+    pub fn to_scale(&self) -> Vec<u8> {
+        let block: ScaleBlock = self.into();
+        block.encode()
+    }
+
+    #[cfg(feature = "scale")]
+    pub fn from_scale(data: &[u8]) -> Self {
+        let block = ScaleBlock::decode(&mut &data[..]).unwrap();
+        block.into()
+    }
+
+    #[cfg(feature = "borsh")]
+    pub fn to_borsh(&self) -> Vec<u8> {
+        let block: BorshBlock = self.into();
+        borsh::to_vec(&block).unwrap()
+    }
+
+    #[cfg(feature = "borsh")]
+    pub fn from_borsh(data: &[u8]) -> Self {
+        let block = BorshBlock::try_from_slice(data).unwrap();
+        block.into()
+    }
+
+    #[cfg(feature = "ssz")]
+    pub fn to_ssz(&self) -> Vec<u8> {
+        let block: SszBlock = self.into();
+        block.check_limits().unwrap();
+        block.as_ssz_bytes()
+    }
+
+    #[cfg(feature = "ssz")]
+    pub fn from_ssz(data: &[u8]) -> Self {
+        let block = SszBlock::from_ssz_bytes(data).unwrap();
+        block.check_limits().unwrap();
+        block.into()
+    }
+
+    #[cfg(feature = "ssz")]
+    pub fn hash_tree_root(&self) -> H256 {
+        let block: SszBlock = self.into();
+        block.hash_tree_root().into()
+    }
+
+    /// `to_ssz` and `hash_tree_root` sharing a single conversion to `SszBlock`.
+    #[cfg(feature = "ssz")]
+    pub fn to_ssz_with_root(&self) -> (Vec<u8>, H256) {
+        let block: SszBlock = self.into();
+        block.check_limits().unwrap();
+        (block.as_ssz_bytes(), block.hash_tree_root().into())
+    }
+
+    #[cfg(feature = "rkyv")]
+    pub fn to_rkyv(&self) -> AlignedVec {
+        rkyv::to_bytes::<_, 4096>(self).unwrap()
+    }
+
+    #[cfg(feature = "rkyv")]
+    pub fn from_rkyv(data: &[u8]) -> Self {
+        let block = rkyv::check_archived_root::<Block>(data).unwrap();
+        block.deserialize(&mut Infallible).unwrap()
+    }
+
+    /// # Safety
+    ///
+    /// `data` is accessed without validation, it must be the output of `to_rkyv`.
+    #[cfg(feature = "rkyv")]
+    pub unsafe fn from_rkyv_unchecked(data: &[u8]) -> Self {
+        let block = rkyv::archived_root::<Block>(data);
+        block.deserialize(&mut Infallible).unwrap()
+    }
+
+    #[cfg(feature = "prost")]
+    pub fn to_prost(&self) -> Vec<u8> {
+        let block: ProstBlock = self.into();
+        block.encode_to_vec()
+    }
+
+    #[cfg(feature = "prost")]
+    pub fn from_prost(data: &[u8]) -> Self {
+        let block = ProstBlock::decode(data).unwrap();
+        block.into()
+    }
+
+    #[cfg(feature = "quick-protobuf")]
+    pub fn to_quick_protobuf(&self) -> Vec<u8> {
+        let block: QuickBlock = self.into();
+        let mut data = Vec::with_capacity(block.get_size());
+        block.write_message(&mut Writer::new(&mut data)).unwrap();
+        data
+    }
+
+    #[cfg(feature = "quick-protobuf")]
+    pub fn from_quick_protobuf(data: &[u8]) -> Self {
+        let block = QuickBlock::from_reader(&mut BytesReader::from_bytes(data), data).unwrap();
+        block.into()
+    }
+
+    #[cfg(feature = "avro")]
+    pub fn to_avro(&self) -> Vec<u8> {
+        to_avro_datum(&avro_schemas().block, AvroValue::from(self)).unwrap()
+    }
+
+    #[cfg(feature = "avro")]
+    pub fn from_avro(data: &[u8]) -> Self {
+        let mut reader = data;
+        from_avro_datum(&avro_schemas().block, &mut reader, None)
+            .unwrap()
+            .into()
+    }
+
+    /// Decodes a datum written by `to_avro` with the reader schema, which has
+    /// an extra defaulted field and so goes through schema resolution.
+    #[cfg(feature = "avro")]
+    pub fn from_avro_resolved(data: &[u8]) -> Self {
+        let schemas = avro_schemas();
+        let mut reader = data;
+        from_avro_datum(&schemas.block, &mut reader, Some(&schemas.block_reader))
+            .unwrap()
+            .into()
+    }
+
+    #[cfg(feature = "avro")]
+    pub fn to_avro_container(&self) -> Vec<u8> {
+        let mut writer = AvroWriter::new(&avro_schemas().block, Vec::new());
+        writer.append(AvroValue::from(self)).unwrap();
+        writer.into_inner().unwrap()
+    }
+
+    #[cfg(feature = "avro")]
+    pub fn from_avro_container(data: &[u8]) -> Self {
+        let mut reader = AvroReader::new(data).unwrap();
+        reader.next().unwrap().unwrap().into()
+    }
+
+    #[cfg(feature = "thrift")]
+    pub fn to_thrift_binary(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        let mut protocol = TBinaryOutputProtocol::new(&mut data, true);
+        ThriftBlock::from(self)
+            .write_to_out_protocol(&mut protocol)
+            .unwrap();
+        data
+    }
+
+    #[cfg(feature = "thrift")]
+    pub fn from_thrift_binary(data: &[u8]) -> Self {
+        let mut protocol = TBinaryInputProtocol::new(data, true);
+        ThriftBlock::read_from_in_protocol(&mut protocol)
+            .unwrap()
+            .into()
+    }
+
+    #[cfg(feature = "thrift")]
+    pub fn to_thrift_compact(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        let mut protocol = TCompactOutputProtocol::new(&mut data);
+        ThriftBlock::from(self)
+            .write_to_out_protocol(&mut protocol)
+            .unwrap();
+        data
+    }
+
+    #[cfg(feature = "thrift")]
+    pub fn from_thrift_compact(data: &[u8]) -> Self {
+        let mut protocol = TCompactInputProtocol::new(data);
+        ThriftBlock::read_from_in_protocol(&mut protocol)
+            .unwrap()
+            .into()
+    }
+
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> Vec<u8> {
+        serde_json::to_vec(&JsonBlock::from(self)).unwrap()
+    }
+
+    #[cfg(feature = "json")]
+    pub fn from_json(data: &[u8]) -> Self {
+        serde_json::from_slice::<JsonBlock>(data).unwrap().into()
+    }
+
+    /// simd-json parses in place, so the input is copied into a mutable buffer
+    /// first.
+    #[cfg(feature = "json")]
+    pub fn from_json_simd(data: &[u8]) -> Self {
+        let mut data = data.to_vec();
+        simd_json::serde::from_slice::<JsonBlock>(&mut data)
+            .unwrap()
+            .into()
+    }
+
+    #[cfg(feature = "bcs")]
+    pub fn to_bcs(&self) -> Vec<u8> {
+        let block: BcsBlock = self.into();
+        bcs::to_bytes(&block).unwrap()
+    }
+
+    #[cfg(feature = "bcs")]
+    pub fn from_bcs(data: &[u8]) -> Self {
+        let block: BcsBlock = bcs::from_bytes(data).unwrap();
+        block.into()
+    }
+
+    #[cfg(feature = "postcard")]
+    pub fn to_postcard(&self) -> Vec<u8> {
+        let block: PostcardBlock = self.into();
+        postcard::to_allocvec(&block).unwrap()
+    }
+
+    #[cfg(feature = "postcard")]
+    pub fn from_postcard(data: &[u8]) -> Self {
+        let block: PostcardBlock = postcard::from_bytes(data).unwrap();
+        block.into()
+    }
+
+    #[cfg(feature = "der")]
+    pub fn to_der(&self) -> Vec<u8> {
+        DerBlock::from(self).to_der().unwrap()
+    }
+
+    #[cfg(feature = "der")]
+    pub fn from_der(data: &[u8]) -> Self {
+        DerBlock::from_der(data).unwrap().into()
+    }
+
+    #[cfg(feature = "xdr")]
+    pub fn to_xdr(&self) -> Vec<u8> {
+        bench_xdr::to_bytes(&XdrBlock::from(self))
+    }
+
+    #[cfg(feature = "xdr")]
+    pub fn from_xdr(data: &[u8]) -> Self {
+        bench_xdr::from_bytes::<XdrBlock>(data).unwrap().into()
+    }
+
+    #[cfg(feature = "raw")]
+    pub fn to_raw(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        self.write_raw(&mut data);
+        data
+    }
+
+    #[cfg(feature = "raw")]
+    pub fn from_raw(data: &[u8]) -> Self {
+        bench_raw::from_bytes(data).unwrap()
+    }
+
+    #[cfg(feature = "flatbuffers")]
+    pub fn to_flatbuffers_struct(&self) -> Vec<u8> {
+        let fbb = &mut FlatBufferBuilder::new();
+
+        let header = {
+            let parent_hash = (&self.header.parent_hash).into();
+            let txs_commit = (&self.header.txs_commit).into();
+            let txs_proposal = (&self.header.txs_proposal).into();
+            let difficulty = FbsStructByte32::new(&self.header.difficulty.into());
+            let proof = fbb.create_vector(&self.header.seal.proof);
+            let cellbase_id = (&self.header.cellbase_id).into();
+            let uncles_hash = (&self.header.uncles_hash).into();
+
+            let mut builder = FbsStructHeaderBuilder::new(fbb);
+            builder.add_version(self.header.version);
+            builder.add_parent_hash(&parent_hash);
+            builder.add_timestamp(self.header.timestamp);
+            builder.add_number(self.header.number);
+            builder.add_txs_commit(&txs_commit);
+            builder.add_txs_proposal(&txs_proposal);
+            builder.add_difficulty(&difficulty);
+            builder.add_nonce(self.header.seal.nonce);
+            builder.add_proof(proof);
+            builder.add_cellbase_id(&cellbase_id);
+            builder.add_uncles_hash(&uncles_hash);
+            builder.finish()
+        };
+
+        let vec = self
+            .transactions
+            .iter()
+            .map(|transaction| {
+                let vec = transaction
+                    .deps
+                    .iter()
+                    .map(Into::into)
+                    .collect::<Vec<FbsStructOutPoint>>();
+                let deps = fbb.create_vector(&vec);
+
+                let vec = transaction
+                    .inputs
+                    .iter()
+                    .map(|input| {
+                        let previous_output = (&input.previous_output).into();
+                        let unlock = fbb.create_vector(&input.unlock);
+                        let mut builder = FbsStructCellInputBuilder::new(fbb);
+                        builder.add_previous_output(&previous_output);
+                        builder.add_unlock(unlock);
+                        builder.finish()
+                    })
+                    .collect::<Vec<_>>();
+                let inputs = fbb.create_vector(&vec);
+
+                let vec = transaction
+                    .outputs
+                    .iter()
+                    .map(|output| {
+                        let data = fbb.create_vector(&output.data);
+                        let lock = (&output.lock).into();
+                        let mut builder = FbsStructCellOutputBuilder::new(fbb);
+                        builder.add_capacity(output.capacity);
+                        builder.add_data(data);
+                        builder.add_lock(&lock);
+                        builder.finish()
+                    })
+                    .collect::<Vec<_>>();
+                let outputs = fbb.create_vector(&vec);
+
+                let mut builder = FbsStructTransactionBuilder::new(fbb);
+                builder.add_version(transaction.version);
+                builder.add_deps(deps);
+                builder.add_inputs(inputs);
+                builder.add_outputs(outputs);
+                builder.finish()
+            })
+            .collect::<Vec<_>>();
+
+        let transactions = fbb.create_vector(&vec);
+
+        let message = {
+            let mut builder = FbsStructBlockBuilder::new(fbb);
+            builder.add_header(header);
+            builder.add_transactions(transactions);
+            builder.finish()
+        };
+        fbb.finish(message, None);
+        fbb.finished_data().to_vec()
+    }
+
+    #[cfg(feature = "flatbuffers")]
+    pub fn from_flatbuffers_struct(data: &[u8]) -> Self {
+        (&get_root::<FbsStructBlock>(data)).into()
+    }
+
+    #[cfg(feature = "molecule")]
+    pub fn to_molecule_struct(&self) -> Vec<u8> {
+        let raw = MolStructRawHeader::new_builder()
+            .version(MolStructUint32::from_slice(&self.header.version.to_le_bytes()).unwrap())
+            .parent_hash(MolStructByte32::from_slice(&self.header.parent_hash).unwrap())
+            .timestamp(MolStructUint64::from_slice(&self.header.timestamp.to_le_bytes()).unwrap())
+            .number(MolStructUint64::from_slice(&self.header.number.to_le_bytes()).unwrap())
+            .txs_commit(MolStructByte32::from_slice(&self.header.txs_commit).unwrap())
+            .txs_proposal(MolStructByte32::from_slice(&self.header.txs_proposal).unwrap())
+            .difficulty(MolStructByte32::from_slice(&H256::from(self.header.difficulty)).unwrap())
+            .cellbase_id(MolStructByte32::from_slice(&self.header.cellbase_id).unwrap())
+            .uncles_hash(MolStructByte32::from_slice(&self.header.uncles_hash).unwrap())
+            .build();
+        let header = MolStructHeader::new_builder()
+            .raw(raw)
+            .nonce(MolStructUint64::from_slice(&self.header.seal.nonce.to_le_bytes()).unwrap())
+            .proof(
+                MolStructBytes::new_builder()
+                    .extend(self.header.seal.proof.clone().into_iter().map(Into::into))
+                    .build(),
+            )
+            .build();
+
+        let transactions: Vec<_> = self
+            .transactions
+            .iter()
+            .map(|tx| {
+                let deps: Vec<_> = tx
+                    .deps
+                    .iter()
+                    .map(|out_point| {
+                        MolStructOutPoint::new_builder()
+                            .hash(MolStructByte32::from_slice(&out_point.hash).unwrap())
+                            .index(
+                                MolStructUint32::from_slice(&out_point.index.to_le_bytes())
+                                    .unwrap(),
+                            )
+                            .build()
+                    })
+                    .collect();
+
+                let inputs: Vec<_> = tx
+                    .inputs
+                    .iter()
+                    .map(|input| {
+                        let previous_output = MolStructOutPoint::new_builder()
+                            .hash(MolStructByte32::from_slice(&input.previous_output.hash).unwrap())
+                            .index(
+                                MolStructUint32::from_slice(
+                                    &input.previous_output.index.to_le_bytes(),
+                                )
+                                .unwrap(),
+                            )
+                            .build();
+                        MolStructCellInput::new_builder()
+                            .previous_output(previous_output)
+                            .unlock(
+                                MolStructBytes::new_builder()
+                                    .extend(input.unlock.clone().into_iter().map(Into::into))
+                                    .build(),
+                            )
+                            .build()
+                    })
+                    .collect();
+
+                let outputs: Vec<_> = tx
+                    .outputs
+                    .iter()
+                    .map(|output| {
+                        MolStructCellOutput::new_builder()
+                            .capacity(
+                                MolStructUint64::from_slice(&output.capacity.to_le_bytes())
+                                    .unwrap(),
+                            )
+                            .data(
+                                MolStructBytes::new_builder()
+                                    .extend(output.data.clone().into_iter().map(Into::into))
+                                    .build(),
+                            )
+                            .lock(MolStructByte32::from_slice(&output.lock).unwrap())
+                            .build()
+                    })
+                    .collect();
+
+                MolStructTransaction::new_builder()
+                    .version(MolStructUint32::from_slice(&tx.version.to_le_bytes()).unwrap())
+                    .deps(MolStructOutPointVec::new_builder().extend(deps).build())
+                    .inputs(MolStructCellInputVec::new_builder().extend(inputs).build())
+                    .outputs(
+                        MolStructCellOutputVec::new_builder()
+                            .extend(outputs)
+                            .build(),
+                    )
+                    .build()
+            })
+            .collect();
+
+        MolStructBlock::new_builder()
+            .header(header)
+            .transactions(
+                MolStructTransactionVec::new_builder()
+                    .extend(transactions)
+                    .build(),
+            )
+            .build()
+            .as_slice()
+            .into()
+    }
+
+    #[cfg(feature = "molecule")]
+    pub fn from_molecule_struct(data: &[u8]) -> Self {
+        let block = MolStructBlockReader::from_slice(data).unwrap();
+        block.into()
+    }
+
+    #[cfg(feature = "prost")]
+    pub fn to_prost_fixed(&self) -> Vec<u8> {
+        let block: ProstFixedBlock = self.into();
+        block.encode_to_vec()
+    }
+
+    #[cfg(feature = "prost")]
+    pub fn from_prost_fixed(data: &[u8]) -> Self {
+        let block = ProstFixedBlock::decode(data).unwrap();
+        block.into()
+    }
+
+    #[cfg(feature = "flexbuffers")]
+    pub fn to_flexbuffers(&self) -> Vec<u8> {
+        let block: FlexBlock = self.into();
+        flexbuffers::to_vec(&block).unwrap()
+    }
+
+    #[cfg(feature = "flexbuffers")]
+    pub fn from_flexbuffers(data: &[u8]) -> Self {
+        let block: FlexBlock = flexbuffers::from_slice(data).unwrap();
+        block.into()
+    }
+
+    #[cfg(feature = "bitcoin")]
+    pub fn to_bitcoin(&self) -> Vec<u8> {
+        bench_bitcoin::to_bytes(&BtcBlock::from(self))
+    }
+
+    #[cfg(feature = "bitcoin")]
+    pub fn from_bitcoin(data: &[u8]) -> Self {
+        bench_bitcoin::from_bytes::<BtcBlock>(data).unwrap().into()
+    }
+
+    #[cfg(feature = "bitcode")]
+    pub fn to_bitcode(&self) -> Vec<u8> {
+        bitcode::encode(&BitcodeBlock::from(self))
+    }
+
+    #[cfg(feature = "bitcode")]
+    pub fn from_bitcode(data: &[u8]) -> Self {
+        bitcode::decode::<BitcodeBlock>(data).unwrap().into()
+    }
+
+    #[cfg(feature = "bson")]
+    pub fn to_bson(&self) -> Vec<u8> {
+        bson::to_vec(&BsonBlock::from(self)).unwrap()
+    }
+
+    #[cfg(feature = "bson")]
+    pub fn from_bson(data: &[u8]) -> Self {
+        bson::from_slice::<BsonBlock>(data).unwrap().into()
+    }
+
+    /// Panics if `transactions`, `deps`, `inputs` or `outputs` has more than
+    /// `u16::MAX` entries, or a byte vector is longer than
+    /// `bench_sbe::MAX_VAR_DATA_LENGTH`.
+    #[cfg(feature = "sbe")]
+    pub fn to_sbe(&self) -> Vec<u8> {
+        bench_sbe::to_bytes(&SbeBlock::from(self)).unwrap()
+    }
+
+    #[cfg(feature = "sbe")]
+    pub fn from_sbe(data: &[u8]) -> Self {
+        bench_sbe::from_bytes::<SbeBlock>(data).unwrap().into()
+    }
+}
+
+impl Transaction {
+    pub fn random(io_size: usize) -> Self {
+        Transaction {
+            version: thread_rng().gen_range(1, 10),
+            deps: (0..io_size).map(|_| OutPoint::random()).collect(),
+            inputs: (0..io_size).map(|_| CellInput::random()).collect(),
+            outputs: (0..io_size).map(|_| CellOutput::random()).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> From<FbsTransaction<'a>> for Transaction {
+    fn from(transaction: FbsTransaction<'a>) -> Self {
+        let deps = FlatbuffersVectorIterator::new(transaction.deps().unwrap())
+            .map(Into::into)
+            .collect();
+
+        let inputs = FlatbuffersVectorIterator::new(transaction.inputs().unwrap())
+            .map(Into::into)
+            .collect();
+
+        let outputs = FlatbuffersVectorIterator::new(transaction.outputs().unwrap())
+            .map(Into::into)
+            .collect();
+
+        Transaction {
+            version: transaction.version(),
+            deps,
+            inputs,
+            outputs,
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl<'a> From<&'a ProtobufTransaction> for Transaction {
+    fn from(transaction: &ProtobufTransaction) -> Self {
+        Transaction {
+            version: transaction.get_version(),
+            deps: transaction.get_deps().iter().map(Into::into).collect(),
+            inputs: transaction.get_inputs().iter().map(Into::into).collect(),
+            outputs: transaction.get_outputs().iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl<'a> From<&'a Transaction> for ProtobufTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        let mut result = ProtobufTransaction::new();
+        result.set_version(transaction.version);
+        result.set_deps(transaction.deps.iter().map(Into::into).collect());
+        result.set_inputs(transaction.inputs.iter().map(Into::into).collect());
+        result.set_outputs(transaction.outputs.iter().map(Into::into).collect());
+        result
+    }
+}
+
+#[cfg(feature = "molecule")]
+impl From<MolTransactionReader<'_>> for Transaction {
+    fn from(transaction: MolTransactionReader) -> Self {
+        let deps = transaction.deps().iter().map(Into::into).collect();
+        let inputs = transaction.inputs().iter().map(Into::into).collect();
+        let outputs = transaction.outputs().iter().map(Into::into).collect();
+
+        Transaction {
+            version: u32::from_le_bytes(transaction.version().as_slice().try_into().unwrap()),
+            deps,
+            inputs,
+            outputs,
+        }
+    }
+}
+
+#[cfg(feature = "scale")]
+impl From<ScaleTransaction> for Transaction {
+    fn from(transaction: ScaleTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "scale")]
+impl<'a> From<&'a Transaction> for ScaleTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        ScaleTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl From<BorshTransaction> for Transaction {
+    fn from(transaction: BorshTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<'a> From<&'a Transaction> for BorshTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        BorshTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<SszTransaction> for Transaction {
+    fn from(transaction: SszTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl<'a> From<&'a Transaction> for SszTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        SszTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<ProstTransaction> for Transaction {
+    fn from(transaction: ProstTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl<'a> From<&'a Transaction> for ProstTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        ProstTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl From<QuickTransaction<'_>> for Transaction {
+    fn from(transaction: QuickTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl<'a> From<&'a Transaction> for QuickTransaction<'a> {
+    fn from(transaction: &'a Transaction) -> Self {
+        QuickTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "avro")]
+impl From<AvroValue> for Transaction {
+    fn from(value: AvroValue) -> Self {
+        let mut fields = bench_avro::record(value);
+        let version = bench_avro::long(fields.take("version")) as u32;
+        let deps = bench_avro::array(fields.take("deps"))
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        let inputs = bench_avro::array(fields.take("inputs"))
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        let outputs = bench_avro::array(fields.take("outputs"))
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Transaction {
+            version,
+            deps,
+            inputs,
+            outputs,
+        }
+    }
+}
+
+#[cfg(feature = "avro")]
+impl<'a> From<&'a Transaction> for AvroValue {
+    fn from(transaction: &Transaction) -> Self {
+        AvroValue::Record(vec![
+            (
+                "version".into(),
+                AvroValue::Long(transaction.version.into()),
+            ),
+            (
+                "deps".into(),
+                AvroValue::Array(transaction.deps.iter().map(Into::into).collect()),
+            ),
+            (
+                "inputs".into(),
+                AvroValue::Array(transaction.inputs.iter().map(Into::into).collect()),
+            ),
+            (
+                "outputs".into(),
+                AvroValue::Array(transaction.outputs.iter().map(Into::into).collect()),
+            ),
+        ])
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<ThriftTransaction> for Transaction {
+    fn from(transaction: ThriftTransaction) -> Self {
+        Transaction {
+            version: transaction.version as u32,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl<'a> From<&'a Transaction> for ThriftTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        ThriftTransaction {
+            version: transaction.version as i32,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<JsonTransaction> for Transaction {
+    fn from(transaction: JsonTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'a> From<&'a Transaction> for JsonTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        JsonTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bcs")]
+impl From<BcsTransaction> for Transaction {
+    fn from(transaction: BcsTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bcs")]
+impl<'a> From<&'a Transaction> for BcsTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        BcsTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl From<PostcardTransaction> for Transaction {
+    fn from(transaction: PostcardTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl<'a> From<&'a Transaction> for PostcardTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        PostcardTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "der")]
+impl From<DerTransaction> for Transaction {
+    fn from(transaction: DerTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "der")]
+impl<'a> From<&'a Transaction> for DerTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        DerTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "xdr")]
+impl From<XdrTransaction> for Transaction {
+    fn from(transaction: XdrTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "xdr")]
+impl<'a> From<&'a Transaction> for XdrTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        XdrTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> From<FbsStructTransaction<'a>> for Transaction {
+    fn from(transaction: FbsStructTransaction<'a>) -> Self {
+        let deps = transaction.deps().unwrap().iter().map(Into::into).collect();
+
+        let inputs = FlatbuffersVectorIterator::new(transaction.inputs().unwrap())
+            .map(Into::into)
+            .collect();
+
+        let outputs = FlatbuffersVectorIterator::new(transaction.outputs().unwrap())
+            .map(Into::into)
+            .collect();
+
+        Transaction {
+            version: transaction.version(),
+            deps,
+            inputs,
+            outputs,
+        }
+    }
+}
+
+#[cfg(feature = "molecule")]
+impl From<MolStructTransactionReader<'_>> for Transaction {
+    fn from(transaction: MolStructTransactionReader) -> Self {
+        let deps = transaction.deps().iter().map(Into::into).collect();
+        let inputs = transaction.inputs().iter().map(Into::into).collect();
+        let outputs = transaction.outputs().iter().map(Into::into).collect();
+
+        Transaction {
+            version: u32::from_le_bytes(transaction.version().as_slice().try_into().unwrap()),
+            deps,
+            inputs,
+            outputs,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<ProstFixedTransaction> for Transaction {
+    fn from(transaction: ProstFixedTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl<'a> From<&'a Transaction> for ProstFixedTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        ProstFixedTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "flexbuffers")]
+impl From<FlexTransaction> for Transaction {
+    fn from(transaction: FlexTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "flexbuffers")]
+impl<'a> From<&'a Transaction> for FlexTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        FlexTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bitcoin")]
+impl From<BtcTransaction> for Transaction {
+    fn from(transaction: BtcTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bitcoin")]
+impl<'a> From<&'a Transaction> for BtcTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        BtcTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bitcode")]
+impl From<BitcodeTransaction> for Transaction {
+    fn from(transaction: BitcodeTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bitcode")]
+impl<'a> From<&'a Transaction> for BitcodeTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        BitcodeTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bson")]
+impl From<BsonTransaction> for Transaction {
+    fn from(transaction: BsonTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "bson")]
+impl<'a> From<&'a Transaction> for BsonTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        BsonTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "sbe")]
+impl From<SbeTransaction> for Transaction {
+    fn from(transaction: SbeTransaction) -> Self {
+        Transaction {
+            version: transaction.version,
+            deps: transaction.deps.into_iter().map(Into::into).collect(),
+            inputs: transaction.inputs.into_iter().map(Into::into).collect(),
+            outputs: transaction.outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "sbe")]
+impl<'a> From<&'a Transaction> for SbeTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        SbeTransaction {
+            version: transaction.version,
+            deps: transaction.deps.iter().map(Into::into).collect(),
+            inputs: transaction.inputs.iter().map(Into::into).collect(),
+            outputs: transaction.outputs.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl OutPoint {
+    pub fn random() -> Self {
+        OutPoint {
+            hash: H256::random(),
+            index: thread_rng().gen_range(1, 10),
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> From<FbsOutPoint<'a>> for OutPoint {
+    fn from(out_point: FbsOutPoint<'a>) -> Self {
+        OutPoint {
+            hash: H256::from_slice(out_point.hash().unwrap()),
+            index: out_point.index(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl<'a> From<&'a ProtobufOutPoint> for OutPoint {
+    fn from(out_point: &ProtobufOutPoint) -> Self {
+        OutPoint {
+            hash: H256::from_slice(out_point.get_hash()),
+            index: out_point.get_index(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl<'a> From<&'a OutPoint> for ProtobufOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        let mut result = ProtobufOutPoint::new();
+        result.set_hash(out_point.hash.to_vec());
+        result.set_index(out_point.index);
+        result
+    }
+}
+
+#[cfg(feature = "molecule")]
+impl From<MolOutPointReader<'_>> for OutPoint {
+    fn from(out_point: MolOutPointReader) -> Self {
+        OutPoint {
+            hash: H256::from_slice(out_point.hash().as_slice()),
+            index: u32::from_le_bytes(out_point.index().as_slice().try_into().unwrap()),
+        }
+    }
+}
+
+#[cfg(feature = "scale")]
+impl From<ScaleOutPoint> for OutPoint {
+    fn from(out_point: ScaleOutPoint) -> Self {
+        OutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "scale")]
+impl<'a> From<&'a OutPoint> for ScaleOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        ScaleOutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl From<BorshOutPoint> for OutPoint {
+    fn from(out_point: BorshOutPoint) -> Self {
+        OutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<'a> From<&'a OutPoint> for BorshOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        BorshOutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<SszOutPoint> for OutPoint {
+    fn from(out_point: SszOutPoint) -> Self {
+        OutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl<'a> From<&'a OutPoint> for SszOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        SszOutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<ProstOutPoint> for OutPoint {
+    fn from(out_point: ProstOutPoint) -> Self {
+        OutPoint {
+            hash: H256::from_slice(&out_point.hash),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl<'a> From<&'a OutPoint> for ProstOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        ProstOutPoint {
+            hash: out_point.hash.to_vec(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl From<QuickOutPoint<'_>> for OutPoint {
+    fn from(out_point: QuickOutPoint) -> Self {
+        OutPoint {
+            hash: H256::from_slice(&out_point.hash),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl<'a> From<&'a OutPoint> for QuickOutPoint<'a> {
+    fn from(out_point: &'a OutPoint) -> Self {
+        QuickOutPoint {
+            hash: Cow::Borrowed(&out_point.hash),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "avro")]
+impl From<AvroValue> for OutPoint {
+    fn from(value: AvroValue) -> Self {
+        let mut fields = bench_avro::record(value);
+        OutPoint {
+            hash: H256::from_slice(&bench_avro::fixed(fields.take("hash"))),
+            index: bench_avro::long(fields.take("index")) as u32,
+        }
+    }
+}
+
+#[cfg(feature = "avro")]
+impl<'a> From<&'a OutPoint> for AvroValue {
+    fn from(out_point: &OutPoint) -> Self {
+        AvroValue::Record(vec![
+            ("hash".into(), AvroValue::Fixed(32, out_point.hash.to_vec())),
+            ("index".into(), AvroValue::Long(out_point.index.into())),
+        ])
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<ThriftOutPoint> for OutPoint {
+    fn from(out_point: ThriftOutPoint) -> Self {
+        OutPoint {
+            hash: H256::from_slice(&out_point.hash),
+            index: out_point.index as u32,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl<'a> From<&'a OutPoint> for ThriftOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        ThriftOutPoint {
+            hash: out_point.hash.to_vec(),
+            index: out_point.index as i32,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<JsonOutPoint> for OutPoint {
+    fn from(out_point: JsonOutPoint) -> Self {
+        OutPoint {
+            hash: out_point.hash.0.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'a> From<&'a OutPoint> for JsonOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        JsonOutPoint {
+            hash: JsonByte32(out_point.hash.into()),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "bcs")]
+impl From<BcsOutPoint> for OutPoint {
+    fn from(out_point: BcsOutPoint) -> Self {
+        OutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "bcs")]
+impl<'a> From<&'a OutPoint> for BcsOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        BcsOutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl From<PostcardOutPoint> for OutPoint {
+    fn from(out_point: PostcardOutPoint) -> Self {
+        OutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl<'a> From<&'a OutPoint> for PostcardOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        PostcardOutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "der")]
+impl From<DerOutPoint> for OutPoint {
+    fn from(out_point: DerOutPoint) -> Self {
+        OutPoint {
+            hash: out_point.hash.0.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "der")]
+impl<'a> From<&'a OutPoint> for DerOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        DerOutPoint {
+            hash: DerByte32(out_point.hash.into()),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "xdr")]
+impl From<XdrOutPoint> for OutPoint {
+    fn from(out_point: XdrOutPoint) -> Self {
+        OutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "xdr")]
+impl<'a> From<&'a OutPoint> for XdrOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        XdrOutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> From<&'a FbsStructOutPoint> for OutPoint {
+    fn from(out_point: &FbsStructOutPoint) -> Self {
+        OutPoint {
+            hash: out_point.hash().into(),
+            index: out_point.index(),
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> From<&'a OutPoint> for FbsStructOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        FbsStructOutPoint::new(&(&out_point.hash).into(), out_point.index)
+    }
+}
+
+#[cfg(feature = "molecule")]
+impl From<MolStructOutPointReader<'_>> for OutPoint {
+    fn from(out_point: MolStructOutPointReader) -> Self {
+        OutPoint {
+            hash: H256::from_slice(out_point.hash().as_slice()),
+            index: u32::from_le_bytes(out_point.index().as_slice().try_into().unwrap()),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<ProstFixedOutPoint> for OutPoint {
+    fn from(out_point: ProstFixedOutPoint) -> Self {
+        OutPoint {
+            hash: H256::from_slice(&out_point.hash),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl<'a> From<&'a OutPoint> for ProstFixedOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        ProstFixedOutPoint {
+            hash: out_point.hash.to_vec(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "flexbuffers")]
+impl From<FlexOutPoint> for OutPoint {
+    fn from(out_point: FlexOutPoint) -> Self {
+        OutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "flexbuffers")]
+impl<'a> From<&'a OutPoint> for FlexOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        FlexOutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "bitcoin")]
+impl From<BtcOutPoint> for OutPoint {
+    fn from(out_point: BtcOutPoint) -> Self {
+        OutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "bitcoin")]
+impl<'a> From<&'a OutPoint> for BtcOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        BtcOutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "bitcode")]
+impl From<BitcodeOutPoint> for OutPoint {
+    fn from(out_point: BitcodeOutPoint) -> Self {
+        OutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "bitcode")]
+impl<'a> From<&'a OutPoint> for BitcodeOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        BitcodeOutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "bson")]
+impl From<BsonOutPoint> for OutPoint {
+    fn from(out_point: BsonOutPoint) -> Self {
+        OutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "bson")]
+impl<'a> From<&'a OutPoint> for BsonOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        BsonOutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "sbe")]
+impl From<SbeOutPoint> for OutPoint {
+    fn from(out_point: SbeOutPoint) -> Self {
+        OutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+#[cfg(feature = "sbe")]
+impl<'a> From<&'a OutPoint> for SbeOutPoint {
+    fn from(out_point: &OutPoint) -> Self {
+        SbeOutPoint {
+            hash: out_point.hash.into(),
+            index: out_point.index,
+        }
+    }
+}
+
+impl CellInput {
+    pub fn random() -> Self {
+        CellInput {
+            previous_output: OutPoint::random(),
+            unlock: thread_rng().sample_iter(&Standard).take(100).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> From<FbsCellInput<'a>> for CellInput {
+    fn from(cell_input: FbsCellInput<'a>) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: H256::from_slice(cell_input.hash().unwrap()),
+                index: cell_input.index(),
+            },
+            unlock: cell_input.unlock().unwrap().to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl<'a> From<&'a ProtobufCellInput> for CellInput {
+    fn from(cell_input: &ProtobufCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: H256::from_slice(cell_input.get_hash()),
+                index: cell_input.get_index(),
+            },
+            unlock: cell_input.get_unlock().to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl<'a> From<&'a CellInput> for ProtobufCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        let mut result = ProtobufCellInput::new();
+        result.set_hash(cell_input.previous_output.hash.to_vec());
+        result.set_index(cell_input.previous_output.index);
+        result.set_unlock(cell_input.unlock.to_vec());
+        result
+    }
+}
+
+#[cfg(feature = "molecule")]
+impl From<MolCellInputReader<'_>> for CellInput {
+    fn from(cell_input: MolCellInputReader) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: H256::from_slice(cell_input.hash().as_slice()),
+                index: u32::from_le_bytes(cell_input.index().as_slice().try_into().unwrap()),
+            },
+            unlock: cell_input.unlock().raw_data().as_ref().into(),
+        }
+    }
+}
+
+#[cfg(feature = "scale")]
+impl From<ScaleCellInput> for CellInput {
+    fn from(cell_input: ScaleCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: cell_input.hash.into(),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock,
+        }
+    }
+}
+
+#[cfg(feature = "scale")]
+impl<'a> From<&'a CellInput> for ScaleCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        ScaleCellInput {
+            hash: cell_input.previous_output.hash.into(),
+            index: cell_input.previous_output.index,
+            unlock: cell_input.unlock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl From<BorshCellInput> for CellInput {
+    fn from(cell_input: BorshCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: cell_input.hash.into(),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock,
+        }
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<'a> From<&'a CellInput> for BorshCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        BorshCellInput {
+            hash: cell_input.previous_output.hash.into(),
+            index: cell_input.previous_output.index,
+            unlock: cell_input.unlock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<SszCellInput> for CellInput {
+    fn from(cell_input: SszCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: cell_input.hash.into(),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock,
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl<'a> From<&'a CellInput> for SszCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        SszCellInput {
+            hash: cell_input.previous_output.hash.into(),
+            index: cell_input.previous_output.index,
+            unlock: cell_input.unlock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<ProstCellInput> for CellInput {
+    fn from(cell_input: ProstCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: H256::from_slice(&cell_input.hash),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl<'a> From<&'a CellInput> for ProstCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        ProstCellInput {
+            hash: cell_input.previous_output.hash.to_vec(),
+            index: cell_input.previous_output.index,
+            unlock: cell_input.unlock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl From<QuickCellInput<'_>> for CellInput {
+    fn from(cell_input: QuickCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: H256::from_slice(&cell_input.hash),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock.into_owned(),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl<'a> From<&'a CellInput> for QuickCellInput<'a> {
+    fn from(cell_input: &'a CellInput) -> Self {
+        QuickCellInput {
+            hash: Cow::Borrowed(&cell_input.previous_output.hash),
+            index: cell_input.previous_output.index,
+            unlock: Cow::Borrowed(&cell_input.unlock),
+        }
+    }
+}
+
+#[cfg(feature = "avro")]
+impl From<AvroValue> for CellInput {
+    fn from(value: AvroValue) -> Self {
+        let mut fields = bench_avro::record(value);
+        CellInput {
+            previous_output: OutPoint {
+                hash: H256::from_slice(&bench_avro::fixed(fields.take("hash"))),
+                index: bench_avro::long(fields.take("index")) as u32,
+            },
+            unlock: bench_avro::bytes(fields.take("unlock")),
+        }
+    }
+}
+
+#[cfg(feature = "avro")]
+impl<'a> From<&'a CellInput> for AvroValue {
+    fn from(cell_input: &CellInput) -> Self {
+        AvroValue::Record(vec![
+            (
+                "hash".into(),
+                AvroValue::Fixed(32, cell_input.previous_output.hash.to_vec()),
+            ),
+            (
+                "index".into(),
+                AvroValue::Long(cell_input.previous_output.index.into()),
+            ),
+            (
+                "unlock".into(),
+                AvroValue::Bytes(cell_input.unlock.to_vec()),
+            ),
+        ])
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<ThriftCellInput> for CellInput {
+    fn from(cell_input: ThriftCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: H256::from_slice(&cell_input.hash),
+                index: cell_input.index as u32,
+            },
+            unlock: cell_input.unlock,
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl<'a> From<&'a CellInput> for ThriftCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        ThriftCellInput {
+            hash: cell_input.previous_output.hash.to_vec(),
+            index: cell_input.previous_output.index as i32,
+            unlock: cell_input.unlock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<JsonCellInput> for CellInput {
+    fn from(cell_input: JsonCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: cell_input.hash.0.into(),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock.0,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'a> From<&'a CellInput> for JsonCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        JsonCellInput {
+            hash: JsonByte32(cell_input.previous_output.hash.into()),
+            index: cell_input.previous_output.index,
+            unlock: JsonBytes(cell_input.unlock.to_vec()),
+        }
+    }
+}
+
+#[cfg(feature = "bcs")]
+impl From<BcsCellInput> for CellInput {
+    fn from(cell_input: BcsCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: cell_input.hash.into(),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock,
+        }
+    }
+}
+
+#[cfg(feature = "bcs")]
+impl<'a> From<&'a CellInput> for BcsCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        BcsCellInput {
+            hash: cell_input.previous_output.hash.into(),
+            index: cell_input.previous_output.index,
+            unlock: cell_input.unlock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl From<PostcardCellInput> for CellInput {
+    fn from(cell_input: PostcardCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: cell_input.hash.into(),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock,
+        }
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl<'a> From<&'a CellInput> for PostcardCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        PostcardCellInput {
+            hash: cell_input.previous_output.hash.into(),
+            index: cell_input.previous_output.index,
+            unlock: cell_input.unlock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "der")]
+impl From<DerCellInput> for CellInput {
+    fn from(cell_input: DerCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: cell_input.hash.0.into(),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock.into_bytes(),
+        }
+    }
+}
+
+#[cfg(feature = "der")]
+impl<'a> From<&'a CellInput> for DerCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        DerCellInput {
+            hash: DerByte32(cell_input.previous_output.hash.into()),
+            index: cell_input.previous_output.index,
+            unlock: OctetString::new(cell_input.unlock.to_vec()).unwrap(),
+        }
+    }
+}
+
+#[cfg(feature = "xdr")]
+impl From<XdrCellInput> for CellInput {
+    fn from(cell_input: XdrCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: cell_input.hash.into(),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock,
+        }
+    }
+}
+
+#[cfg(feature = "xdr")]
+impl<'a> From<&'a CellInput> for XdrCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        XdrCellInput {
+            hash: cell_input.previous_output.hash.into(),
+            index: cell_input.previous_output.index,
+            unlock: cell_input.unlock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> From<FbsStructCellInput<'a>> for CellInput {
+    fn from(cell_input: FbsStructCellInput<'a>) -> Self {
+        CellInput {
+            previous_output: cell_input.previous_output().unwrap().into(),
+            unlock: cell_input.unlock().unwrap().to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "molecule")]
+impl From<MolStructCellInputReader<'_>> for CellInput {
+    fn from(cell_input: MolStructCellInputReader) -> Self {
+        CellInput {
+            previous_output: cell_input.previous_output().into(),
+            unlock: cell_input.unlock().raw_data().as_ref().into(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<ProstFixedCellInput> for CellInput {
+    fn from(cell_input: ProstFixedCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: H256::from_slice(&cell_input.hash),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl<'a> From<&'a CellInput> for ProstFixedCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        ProstFixedCellInput {
+            hash: cell_input.previous_output.hash.to_vec(),
+            index: cell_input.previous_output.index,
+            unlock: cell_input.unlock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "flexbuffers")]
+impl From<FlexCellInput> for CellInput {
+    fn from(cell_input: FlexCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: cell_input.hash.into(),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock,
+        }
+    }
+}
+
+#[cfg(feature = "flexbuffers")]
+impl<'a> From<&'a CellInput> for FlexCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        FlexCellInput {
+            hash: cell_input.previous_output.hash.into(),
+            index: cell_input.previous_output.index,
+            unlock: cell_input.unlock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "bitcoin")]
+impl From<BtcCellInput> for CellInput {
+    fn from(cell_input: BtcCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: cell_input.hash.into(),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock,
+        }
+    }
+}
+
+#[cfg(feature = "bitcoin")]
+impl<'a> From<&'a CellInput> for BtcCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        BtcCellInput {
+            hash: cell_input.previous_output.hash.into(),
+            index: cell_input.previous_output.index,
+            unlock: cell_input.unlock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "bitcode")]
+impl From<BitcodeCellInput> for CellInput {
+    fn from(cell_input: BitcodeCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: cell_input.hash.into(),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock,
+        }
+    }
+}
+
+#[cfg(feature = "bitcode")]
+impl<'a> From<&'a CellInput> for BitcodeCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        BitcodeCellInput {
+            hash: cell_input.previous_output.hash.into(),
+            index: cell_input.previous_output.index,
+            unlock: cell_input.unlock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "bson")]
+impl From<BsonCellInput> for CellInput {
+    fn from(cell_input: BsonCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: cell_input.hash.into(),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock,
+        }
+    }
+}
+
+#[cfg(feature = "bson")]
+impl<'a> From<&'a CellInput> for BsonCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        BsonCellInput {
+            hash: cell_input.previous_output.hash.into(),
+            index: cell_input.previous_output.index,
+            unlock: cell_input.unlock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "sbe")]
+impl From<SbeCellInput> for CellInput {
+    fn from(cell_input: SbeCellInput) -> Self {
+        CellInput {
+            previous_output: OutPoint {
+                hash: cell_input.hash.into(),
+                index: cell_input.index,
+            },
+            unlock: cell_input.unlock,
+        }
+    }
+}
+
+#[cfg(feature = "sbe")]
+impl<'a> From<&'a CellInput> for SbeCellInput {
+    fn from(cell_input: &CellInput) -> Self {
+        SbeCellInput {
+            hash: cell_input.previous_output.hash.into(),
+            index: cell_input.previous_output.index,
+            unlock: cell_input.unlock.to_vec(),
+        }
+    }
+}
+
+impl CellOutput {
+    pub fn random() -> Self {
+        CellOutput {
+            capacity: thread_rng().gen_range(600, 1000),
+            data: thread_rng().sample_iter(&Standard).take(600).collect(),
+            lock: H256::random(),
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> From<FbsCellOutput<'a>> for CellOutput {
+    fn from(cell_output: FbsCellOutput<'a>) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity(),
+            data: cell_output.data().unwrap().to_vec(),
+            lock: H256::from_slice(cell_output.lock().unwrap()),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl<'a> From<&'a ProtobufCellOutput> for CellOutput {
+    fn from(cell_output: &ProtobufCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.get_capacity(),
+            data: cell_output.get_data().to_vec(),
+            lock: H256::from_slice(cell_output.get_lock()),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl<'a> From<&'a CellOutput> for ProtobufCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        let mut result = ProtobufCellOutput::new();
+        result.set_capacity(cell_output.capacity);
+        result.set_data(cell_output.data.to_vec());
+        result.set_lock(cell_output.lock.to_vec());
+        result
+    }
+}
+
+#[cfg(feature = "molecule")]
+impl From<MolCellOutputReader<'_>> for CellOutput {
+    fn from(cell_output: MolCellOutputReader) -> Self {
+        CellOutput {
+            capacity: u64::from_le_bytes(cell_output.capacity().as_slice().try_into().unwrap()),
+            data: cell_output.data().raw_data().as_ref().into(),
+            lock: H256::from_slice(cell_output.lock().as_slice()),
+        }
+    }
+}
+
+#[cfg(feature = "scale")]
+impl From<ScaleCellOutput> for CellOutput {
+    fn from(cell_output: ScaleCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data,
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "scale")]
+impl<'a> From<&'a CellOutput> for ScaleCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        ScaleCellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.to_vec(),
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl From<BorshCellOutput> for CellOutput {
+    fn from(cell_output: BorshCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data,
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<'a> From<&'a CellOutput> for BorshCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        BorshCellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.to_vec(),
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl From<SszCellOutput> for CellOutput {
+    fn from(cell_output: SszCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data,
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "ssz")]
+impl<'a> From<&'a CellOutput> for SszCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        SszCellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.to_vec(),
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<ProstCellOutput> for CellOutput {
+    fn from(cell_output: ProstCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data,
+            lock: H256::from_slice(&cell_output.lock),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl<'a> From<&'a CellOutput> for ProstCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        ProstCellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.to_vec(),
+            lock: cell_output.lock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl From<QuickCellOutput<'_>> for CellOutput {
+    fn from(cell_output: QuickCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.into_owned(),
+            lock: H256::from_slice(&cell_output.lock),
+        }
+    }
+}
+
+#[cfg(feature = "quick-protobuf")]
+impl<'a> From<&'a CellOutput> for QuickCellOutput<'a> {
+    fn from(cell_output: &'a CellOutput) -> Self {
+        QuickCellOutput {
+            capacity: cell_output.capacity,
+            data: Cow::Borrowed(&cell_output.data),
+            lock: Cow::Borrowed(&cell_output.lock),
+        }
+    }
+}
+
+#[cfg(feature = "avro")]
+impl From<AvroValue> for CellOutput {
+    fn from(value: AvroValue) -> Self {
+        let mut fields = bench_avro::record(value);
+        CellOutput {
+            capacity: bench_avro::long(fields.take("capacity")) as u64,
+            data: bench_avro::bytes(fields.take("data")),
+            lock: H256::from_slice(&bench_avro::fixed(fields.take("lock"))),
+        }
+    }
+}
+
+#[cfg(feature = "avro")]
+impl<'a> From<&'a CellOutput> for AvroValue {
+    fn from(cell_output: &CellOutput) -> Self {
+        AvroValue::Record(vec![
+            (
+                "capacity".into(),
+                AvroValue::Long(cell_output.capacity as i64),
+            ),
+            ("data".into(), AvroValue::Bytes(cell_output.data.to_vec())),
+            (
+                "lock".into(),
+                AvroValue::Fixed(32, cell_output.lock.to_vec()),
+            ),
+        ])
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl From<ThriftCellOutput> for CellOutput {
+    fn from(cell_output: ThriftCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity as u64,
+            data: cell_output.data,
+            lock: H256::from_slice(&cell_output.lock),
+        }
+    }
+}
+
+#[cfg(feature = "thrift")]
+impl<'a> From<&'a CellOutput> for ThriftCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        ThriftCellOutput {
+            capacity: cell_output.capacity as i64,
+            data: cell_output.data.to_vec(),
+            lock: cell_output.lock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<JsonCellOutput> for CellOutput {
+    fn from(cell_output: JsonCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.0,
+            lock: cell_output.lock.0.into(),
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'a> From<&'a CellOutput> for JsonCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        JsonCellOutput {
+            capacity: cell_output.capacity,
+            data: JsonBytes(cell_output.data.to_vec()),
+            lock: JsonByte32(cell_output.lock.into()),
+        }
+    }
+}
+
+#[cfg(feature = "bcs")]
+impl From<BcsCellOutput> for CellOutput {
+    fn from(cell_output: BcsCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data,
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "bcs")]
+impl<'a> From<&'a CellOutput> for BcsCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        BcsCellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.to_vec(),
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl From<PostcardCellOutput> for CellOutput {
+    fn from(cell_output: PostcardCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data,
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl<'a> From<&'a CellOutput> for PostcardCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        PostcardCellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.to_vec(),
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "der")]
+impl From<DerCellOutput> for CellOutput {
+    fn from(cell_output: DerCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.into_bytes(),
+            lock: cell_output.lock.0.into(),
+        }
+    }
+}
+
+#[cfg(feature = "der")]
+impl<'a> From<&'a CellOutput> for DerCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        DerCellOutput {
+            capacity: cell_output.capacity,
+            data: OctetString::new(cell_output.data.to_vec()).unwrap(),
+            lock: DerByte32(cell_output.lock.into()),
+        }
+    }
+}
+
+#[cfg(feature = "xdr")]
+impl From<XdrCellOutput> for CellOutput {
+    fn from(cell_output: XdrCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data,
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "xdr")]
+impl<'a> From<&'a CellOutput> for XdrCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        XdrCellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.to_vec(),
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> From<FbsStructCellOutput<'a>> for CellOutput {
+    fn from(cell_output: FbsStructCellOutput<'a>) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity(),
+            data: cell_output.data().unwrap().to_vec(),
+            lock: cell_output.lock().unwrap().into(),
+        }
+    }
+}
+
+#[cfg(feature = "molecule")]
+impl From<MolStructCellOutputReader<'_>> for CellOutput {
+    fn from(cell_output: MolStructCellOutputReader) -> Self {
+        CellOutput {
+            capacity: u64::from_le_bytes(cell_output.capacity().as_slice().try_into().unwrap()),
+            data: cell_output.data().raw_data().as_ref().into(),
+            lock: H256::from_slice(cell_output.lock().as_slice()),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<ProstFixedCellOutput> for CellOutput {
+    fn from(cell_output: ProstFixedCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data,
+            lock: H256::from_slice(&cell_output.lock),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl<'a> From<&'a CellOutput> for ProstFixedCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        ProstFixedCellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.to_vec(),
+            lock: cell_output.lock.to_vec(),
+        }
+    }
+}
+
+#[cfg(feature = "flexbuffers")]
+impl From<FlexCellOutput> for CellOutput {
+    fn from(cell_output: FlexCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data,
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "flexbuffers")]
+impl<'a> From<&'a CellOutput> for FlexCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        FlexCellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.to_vec(),
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "bitcoin")]
+impl From<BtcCellOutput> for CellOutput {
+    fn from(cell_output: BtcCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data,
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "bitcoin")]
+impl<'a> From<&'a CellOutput> for BtcCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        BtcCellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.to_vec(),
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "bitcode")]
+impl From<BitcodeCellOutput> for CellOutput {
+    fn from(cell_output: BitcodeCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data,
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "bitcode")]
+impl<'a> From<&'a CellOutput> for BitcodeCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        BitcodeCellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.to_vec(),
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "bson")]
+impl From<BsonCellOutput> for CellOutput {
+    fn from(cell_output: BsonCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data,
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "bson")]
+impl<'a> From<&'a CellOutput> for BsonCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        BsonCellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.to_vec(),
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "sbe")]
+impl From<SbeCellOutput> for CellOutput {
+    fn from(cell_output: SbeCellOutput) -> Self {
+        CellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data,
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(feature = "sbe")]
+impl<'a> From<&'a CellOutput> for SbeCellOutput {
+    fn from(cell_output: &CellOutput) -> Self {
+        SbeCellOutput {
+            capacity: cell_output.capacity,
+            data: cell_output.data.to_vec(),
+            lock: cell_output.lock.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: alloc_counter::CountingAllocator = alloc_counter::CountingAllocator;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "flatbuffers")]
+    mod flatbuffers {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_flatbuffers();
+            assert_eq!(header, Header::from_flatbuffers(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_flatbuffers();
+            assert_eq!(block, Block::from_flatbuffers(&data));
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100)
+                .map(|_| Header::random().to_flatbuffers().len())
+                .sum();
+            println!("flatbuffers header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_flatbuffers().len())
+                .sum();
+            println!("flatbuffers block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "protobuf")]
+    mod protobuf {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_protobuf();
+            assert_eq!(header, Header::from_protobuf(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_protobuf();
+            assert_eq!(block, Block::from_protobuf(&data));
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100).map(|_| Header::random().to_protobuf().len()).sum();
+            println!("protobuf header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_protobuf().len())
+                .sum();
+            println!("protobuf block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "molecule")]
+    mod molecule {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_molecule();
+            assert_eq!(header, Header::from_molecule(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_molecule();
+            assert_eq!(block, Block::from_molecule(&data));
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100).map(|_| Header::random().to_molecule().len()).sum();
+            println!("molecule header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_molecule().len())
+                .sum();
+            println!("molecule block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "scale")]
+    mod scale {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_scale();
+            assert_eq!(header, Header::from_scale(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_scale();
+            assert_eq!(block, Block::from_scale(&data));
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100).map(|_| Header::random().to_scale().len()).sum();
+            println!("scale header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_scale().len())
+                .sum();
+            println!("scale block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "borsh")]
+    mod borsh {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_borsh();
+            assert_eq!(header, Header::from_borsh(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_borsh();
+            assert_eq!(block, Block::from_borsh(&data));
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100).map(|_| Header::random().to_borsh().len()).sum();
+            println!("borsh header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_borsh().len())
+                .sum();
+            println!("borsh block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "ssz")]
+    mod ssz {
+        use super::*;
+        use crate::bench_ssz::{LengthError, MAX_IO_LENGTH};
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_ssz();
+            assert_eq!(header, Header::from_ssz(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_ssz();
+            assert_eq!(block, Block::from_ssz(&data));
+        }
+
+        #[test]
+        fn hash_tree_root() {
+            let mut block = Block::random(100, 3);
+            let root = block.hash_tree_root();
+            assert_eq!(root, Block::from_ssz(&block.to_ssz()).hash_tree_root());
+            assert_eq!((block.to_ssz(), root), block.to_ssz_with_root());
+
+            block.transactions[0].outputs[0].capacity += 1;
+            assert_ne!(root, block.hash_tree_root());
+        }
+
+        #[test]
+        fn known_hash_tree_root() {
+            // Roots of this block computed by a separate, naive implementation
+            // of the SSZ spec that hashes the whole padded tree.
+            let block = SszBlock {
+                header: SszHeader {
+                    version: 1,
+                    parent_hash: [1; 32],
+                    timestamp: 1_500_000_000,
+                    number: 42,
+                    txs_commit: [2; 32],
+                    txs_proposal: [3; 32],
+                    difficulty: [4; 32],
+                    nonce: 7,
+                    proof: vec![5; 40],
+                    cellbase_id: [6; 32],
+                    uncles_hash: [7; 32],
+                },
+                transactions: vec![
+                    SszTransaction {
+                        version: 1,
+                        deps: vec![SszOutPoint {
+                            hash: [8; 32],
+                            index: 0,
+                        }],
+                        inputs: vec![SszCellInput {
+                            hash: [9; 32],
+                            index: 1,
+                            unlock: vec![10; 3],
+                        }],
+                        outputs: vec![SszCellOutput {
+                            capacity: 100,
+                            data: vec![11; 33],
+                            lock: [12; 32],
+                        }],
+                    },
+                    SszTransaction {
+                        version: 2,
+                        deps: vec![],
+                        inputs: vec![],
+                        outputs: vec![],
+                    },
+                ],
+            };
+            assert_eq!(
+                block.header.hash_tree_root(),
+                [
+                    0x51, 0x3d, 0xd6, 0xba, 0x59, 0xd1, 0xc9, 0x1c, 0xe7, 0x61, 0xa1, 0x77, 0x4f,
+                    0x2f, 0xf5, 0x7f, 0xd2, 0x26, 0x22, 0x22, 0x8c, 0xba, 0x19, 0xce, 0x68, 0x0e,
+                    0xee, 0x3f, 0x4a, 0xaa, 0xa8, 0x10,
+                ]
+            );
+            assert_eq!(
+                block.hash_tree_root(),
+                [
+                    0x22, 0x9e, 0xfa, 0x7d, 0xf0, 0x1e, 0x47, 0xa2, 0x6d, 0xe0, 0xef, 0xef, 0x72,
+                    0x04, 0x6d, 0xb3, 0x1b, 0x9f, 0x58, 0xd6, 0x94, 0x8a, 0x7f, 0x4b, 0x26, 0xa3,
+                    0xcd, 0xf9, 0x1e, 0x70, 0xa6, 0x4d,
+                ]
+            );
+        }
+
+        #[test]
+        fn limits() {
+            let mut block = SszBlock::from(&Block::random(1, 3));
+            assert_eq!(block.check_limits(), Ok(()));
+
+            block.transactions[0].deps = (0..=MAX_IO_LENGTH)
+                .map(|index| SszOutPoint {
+                    hash: [0; 32],
+                    index: index as u32,
+                })
+                .collect();
+            let error = LengthError {
+                length: MAX_IO_LENGTH + 1,
+                limit: MAX_IO_LENGTH,
+            };
+            assert_eq!(block.check_limits(), Err(error));
+
+            // The ssz crate decodes the list anyway, the check has to follow.
+            let decoded = SszBlock::from_ssz_bytes(&block.as_ssz_bytes()).unwrap();
+            assert!(decoded.check_limits().is_err());
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100).map(|_| Header::random().to_ssz().len()).sum();
+            println!("ssz header size: {}", size);
+
+            let size: usize = (0..100).map(|_| Block::random(100, 3).to_ssz().len()).sum();
+            println!("ssz block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "rkyv")]
+    mod rkyv {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_rkyv();
+            assert_eq!(header, Header::from_rkyv(&data));
+            assert_eq!(header, unsafe { Header::from_rkyv_unchecked(&data) });
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_rkyv();
+            assert_eq!(block, Block::from_rkyv(&data));
+            assert_eq!(block, unsafe { Block::from_rkyv_unchecked(&data) });
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100).map(|_| Header::random().to_rkyv().len()).sum();
+            println!("rkyv header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_rkyv().len())
+                .sum();
+            println!("rkyv block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "prost")]
+    mod prost {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_prost();
+            assert_eq!(header, Header::from_prost(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_prost();
+            assert_eq!(block, Block::from_prost(&data));
+        }
+
+        #[test]
+        #[cfg(feature = "protobuf")]
+        fn differential_header() {
+            let header = Header::random();
+            assert_eq!(header, Header::from_protobuf(&header.to_prost()));
+            assert_eq!(header, Header::from_prost(&header.to_protobuf()));
+        }
+
+        #[test]
+        #[cfg(feature = "protobuf")]
+        fn differential_block() {
+            let block = Block::random(100, 3);
+            assert_eq!(block, Block::from_protobuf(&block.to_prost()));
+            assert_eq!(block, Block::from_prost(&block.to_protobuf()));
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100).map(|_| Header::random().to_prost().len()).sum();
+            println!("prost header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_prost().len())
+                .sum();
+            println!("prost block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "quick-protobuf")]
+    mod quick_protobuf {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_quick_protobuf();
+            assert_eq!(header, Header::from_quick_protobuf(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_quick_protobuf();
+            assert_eq!(block, Block::from_quick_protobuf(&data));
+        }
+
+        #[test]
+        fn borrowed_decode() {
+            let block = Block::random(100, 3);
+            let data = block.to_quick_protobuf();
+            let message =
+                QuickBlock::from_reader(&mut BytesReader::from_bytes(&data), &data).unwrap();
+            let data_range = data.as_ptr_range();
+            let output = &message.transactions[0].outputs[0];
+            match output.data {
+                Cow::Borrowed(bytes) => assert!(data_range.contains(&bytes.as_ptr())),
+                Cow::Owned(_) => panic!("bytes field should borrow from the input"),
+            }
+            assert_eq!(block, Block::from(message));
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100)
+                .map(|_| Header::random().to_quick_protobuf().len())
+                .sum();
+            println!("quick-protobuf header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_quick_protobuf().len())
+                .sum();
+            println!("quick-protobuf block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "avro")]
+    mod avro {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_avro();
+            assert_eq!(header, Header::from_avro(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_avro();
+            assert_eq!(block, Block::from_avro(&data));
+        }
+
+        #[test]
+        fn ser_de_block_resolved() {
+            let block = Block::random(100, 3);
+            let data = block.to_avro();
+            assert_eq!(block, Block::from_avro_resolved(&data));
+        }
+
+        #[test]
+        fn reader_schema() {
+            // bench_reader.avsc is bench.avsc with `epoch` appended to Header.
+            let schemas = avro_schemas();
+            let epoch = r#",{"name":"epoch","type":"long"}"#;
+            let reader = schemas.block_reader.canonical_form();
+            assert_eq!(reader.matches(epoch).count(), 1);
+            assert_eq!(reader.replace(epoch, ""), schemas.block.canonical_form());
+        }
+
+        #[test]
+        fn ser_de_block_container() {
+            let block = Block::random(100, 3);
+            let data = block.to_avro_container();
+            assert_eq!(block, Block::from_avro_container(&data));
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100).map(|_| Header::random().to_avro().len()).sum();
+            println!("avro header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_avro().len())
+                .sum();
+            println!("avro block size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_avro_container().len())
+                .sum();
+            println!("avro container block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "thrift")]
+    mod thrift {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_thrift_binary();
+            assert_eq!(header, Header::from_thrift_binary(&data));
+            let data = header.to_thrift_compact();
+            assert_eq!(header, Header::from_thrift_compact(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_thrift_binary();
+            assert_eq!(block, Block::from_thrift_binary(&data));
+            let data = block.to_thrift_compact();
+            assert_eq!(block, Block::from_thrift_compact(&data));
+        }
+
+        #[test]
+        fn ser_de_sync_message() {
+            use crate::bench_thrift::{GetHeaders, Headers, SyncMessage, SyncPayload};
+
+            let headers = (0..3).map(|_| (&Header::random()).into()).collect();
+            let get_headers = GetHeaders::new(
+                0,
+                (0..3).map(|_| H256::random().to_vec()).collect(),
+                H256::random().to_vec(),
+            );
+            for payload in [
+                SyncPayload::GetHeaders(get_headers),
+                SyncPayload::Headers(Headers::new(headers)),
+            ] {
+                let message = SyncMessage::new(payload);
+
+                let mut data = Vec::new();
+                let mut protocol = TBinaryOutputProtocol::new(&mut data, true);
+                message.write_to_out_protocol(&mut protocol).unwrap();
+                let mut protocol = TBinaryInputProtocol::new(&data[..], true);
+                assert_eq!(
+                    message,
+                    SyncMessage::read_from_in_protocol(&mut protocol).unwrap()
+                );
+
+                let mut data = Vec::new();
+                let mut protocol = TCompactOutputProtocol::new(&mut data);
+                message.write_to_out_protocol(&mut protocol).unwrap();
+                let mut protocol = TCompactInputProtocol::new(&data[..]);
+                assert_eq!(
+                    message,
+                    SyncMessage::read_from_in_protocol(&mut protocol).unwrap()
+                );
+            }
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100)
+                .map(|_| Header::random().to_thrift_binary().len())
+                .sum();
+            println!("thrift binary header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Header::random().to_thrift_compact().len())
+                .sum();
+            println!("thrift compact header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_thrift_binary().len())
+                .sum();
+            println!("thrift binary block size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_thrift_compact().len())
+                .sum();
+            println!("thrift compact block size: {}", size);
+        }
+
+        /// Compact field headers pack the field id delta and the type into one
+        /// byte, just like a protobuf tag does for small field numbers. Compact
+        /// still comes out smaller because it ends nested structs with a stop
+        /// byte instead of a length prefix.
+        #[test]
+        #[cfg(feature = "protobuf")]
+        fn compact_vs_protobuf() {
+            let (compact, protobuf) = (0..100)
+                .map(|_| Block::random(100, 3))
+                .map(|block| (block.to_thrift_compact().len(), block.to_protobuf().len()))
+                .fold((0, 0), |(a, b), (c, d)| (a + c, b + d));
+            println!("thrift compact block size: {}", compact);
+            println!("protobuf block size: {}", protobuf);
+            assert!(compact < protobuf);
+        }
+    }
+
+    #[cfg(feature = "json")]
+    mod json {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_json();
+            assert_eq!(header, Header::from_json(&data));
+            assert_eq!(header, Header::from_json_simd(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_json();
+            assert_eq!(block, Block::from_json(&data));
+            assert_eq!(block, Block::from_json_simd(&data));
+        }
+
+        #[test]
+        fn hex_encoding() {
+            let mut header = Header::random();
+            header.seal.proof = vec![0x01, 0xab];
+            let json: serde_json::Value = serde_json::from_slice(&header.to_json()).unwrap();
+            assert_eq!(json["proof"], "0x01ab");
+            assert_eq!(
+                json["parent_hash"],
+                format!("0x{}", faster_hex::hex_string(&header.parent_hash))
+            );
+
+            header.seal.proof = Vec::new();
+            let json: serde_json::Value = serde_json::from_slice(&header.to_json()).unwrap();
+            assert_eq!(json["proof"], "0x");
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100).map(|_| Header::random().to_json().len()).sum();
+            println!("json header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_json().len())
+                .sum();
+            println!("json block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "bcs")]
+    mod bcs {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_bcs();
+            assert_eq!(header, Header::from_bcs(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_bcs();
+            assert_eq!(block, Block::from_bcs(&data));
+        }
+
+        #[test]
+        fn re_encode() {
+            let header = Header::random();
+            let data = header.to_bcs();
+            assert_eq!(data, Header::from_bcs(&data).to_bcs());
+
+            let block = Block::random(100, 3);
+            let data = block.to_bcs();
+            assert_eq!(data, Block::from_bcs(&data).to_bcs());
+        }
+
+        #[test]
+        fn reject_non_canonical() {
+            // `proof` starts after the fixed-size fields, its 32-byte length
+            // fits in a single ULEB128 byte.
+            const PROOF_OFFSET: usize = 4 + 32 + 8 + 8 + 32 + 32 + 32 + 8;
+            let data = Header::random().to_bcs();
+            assert_eq!(data[PROOF_OFFSET], 32);
+
+            let mut non_minimal = data[..PROOF_OFFSET].to_vec();
+            non_minimal.extend_from_slice(&[0x80 | 32, 0x00]);
+            non_minimal.extend_from_slice(&data[PROOF_OFFSET + 1..]);
+            assert_eq!(
+                ::bcs::from_bytes::<BcsHeader>(&non_minimal).err(),
+                Some(::bcs::Error::NonCanonicalUleb128Encoding)
+            );
+
+            let mut trailing = data.clone();
+            trailing.push(0);
+            assert_eq!(
+                ::bcs::from_bytes::<BcsHeader>(&trailing).err(),
+                Some(::bcs::Error::RemainingInput)
+            );
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100).map(|_| Header::random().to_bcs().len()).sum();
+            println!("bcs header size: {}", size);
+
+            let size: usize = (0..100).map(|_| Block::random(100, 3).to_bcs().len()).sum();
+            println!("bcs block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "postcard")]
+    mod postcard {
+        use super::*;
+        use crate::bench_postcard::MAX_PROOF_LENGTH;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_postcard();
+            assert_eq!(header, Header::from_postcard(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_postcard();
+            assert_eq!(block, Block::from_postcard(&data));
+        }
+
+        #[test]
+        fn heapless_header() {
+            let header = Header::random();
+            let data = header.to_postcard();
+            assert_eq!(header, Header::from_postcard_heapless(&data).into());
+        }
+
+        #[test]
+        fn heapless_header_proof_bound() {
+            let mut header = Header::random();
+            header.seal.proof = vec![0; MAX_PROOF_LENGTH];
+            let data = header.to_postcard();
+            assert!(PostcardHeaplessHeader::from_postcard(&data).is_ok());
+
+            header.seal.proof.push(0);
+            let data = header.to_postcard();
+            assert!(PostcardHeaplessHeader::from_postcard(&data).is_err());
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100).map(|_| Header::random().to_postcard().len()).sum();
+            println!("postcard header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_postcard().len())
+                .sum();
+            println!("postcard block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "der")]
+    mod der {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_der();
+            assert_eq!(header, Header::from_der(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_der();
+            assert_eq!(block, Block::from_der(&data));
+        }
+
+        #[test]
+        fn re_encode() {
+            let header = Header::random();
+            let data = header.to_der();
+            assert_eq!(data, Header::from_der(&data).to_der());
+
+            let block = Block::random(100, 3);
+            let data = block.to_der();
+            assert_eq!(data, Block::from_der(&data).to_der());
+        }
+
+        #[test]
+        fn reject_non_canonical() {
+            let out_point = DerOutPoint {
+                hash: DerByte32([0; 32]),
+                index: 1,
+            };
+            let data = out_point.to_der().unwrap();
+            // SEQUENCE { OCTET STRING (32 bytes), INTEGER 1 }
+            assert_eq!(&data[..4], &[0x30, 0x25, 0x04, 0x20]);
+            assert_eq!(&data[36..], &[0x02, 0x01, 0x01]);
+
+            // INTEGER with a redundant leading zero byte.
+            let mut non_minimal = vec![0x30, 0x26];
+            non_minimal.extend_from_slice(&data[2..36]);
+            non_minimal.extend_from_slice(&[0x02, 0x02, 0x00, 0x01]);
+            assert!(DerOutPoint::from_der(&non_minimal).is_err());
+
+            // Long form length where the short form fits.
+            let mut long_length = vec![0x30, 0x81, 0x25];
+            long_length.extend_from_slice(&data[2..]);
+            assert!(DerOutPoint::from_der(&long_length).is_err());
+
+            // Hash shorter than `OCTET STRING (SIZE (32))`.
+            let mut short_hash = vec![0x30, 0x24, 0x04, 0x1f];
+            short_hash.extend_from_slice(&data[5..]);
+            assert!(DerOutPoint::from_der(&short_hash).is_err());
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100).map(|_| Header::random().to_der().len()).sum();
+            println!("der header size: {}", size);
+
+            let size: usize = (0..100).map(|_| Block::random(100, 3).to_der().len()).sum();
+            println!("der block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "xdr")]
+    mod xdr {
+        use super::*;
+        use crate::bench_xdr::Error;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_xdr();
+            assert_eq!(header, Header::from_xdr(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_xdr();
+            assert_eq!(block, Block::from_xdr(&data));
+        }
+
+        #[test]
+        fn alignment() {
+            let mut block = Block::random(10, 3);
+            block.header.seal.proof = vec![1; 5];
+            block.transactions[0].inputs[0].unlock = vec![2; 6];
+            block.transactions[0].outputs[0].data = vec![3; 7];
+            assert_eq!(block.header.to_xdr().len() % 4, 0);
+            assert_eq!(block.to_xdr().len() % 4, 0);
+        }
+
+        #[test]
+        fn reject_non_canonical() {
+            // `proof` is the only variable field of the header, the padding of
+            // a 5-byte proof sits right before the two trailing hashes.
+            let mut header = Header::random();
+            header.seal.proof = vec![1; 5];
+            let data = header.to_xdr();
+            let padding = data.len() - 64 - 3;
+            assert_eq!(&data[padding..padding + 3], &[0, 0, 0]);
+
+            let mut non_zero_padding = data.clone();
+            non_zero_padding[padding] = 1;
+            assert_eq!(
+                bench_xdr::from_bytes::<XdrHeader>(&non_zero_padding).err(),
+                Some(Error::NonZeroPadding)
+            );
+
+            let mut trailing = data.clone();
+            trailing.extend_from_slice(&[0; 4]);
+            assert_eq!(
+                bench_xdr::from_bytes::<XdrHeader>(&trailing).err(),
+                Some(Error::TrailingBytes)
+            );
+
+            assert_eq!(
+                bench_xdr::from_bytes::<XdrHeader>(&data[..data.len() - 1]).err(),
+                Some(Error::UnexpectedEof)
+            );
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100).map(|_| Header::random().to_xdr().len()).sum();
+            println!("xdr header size: {}", size);
+
+            let size: usize = (0..100).map(|_| Block::random(100, 3).to_xdr().len()).sum();
+            println!("xdr block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "raw")]
+    mod raw {
+        use super::*;
+        use crate::bench_raw::Error;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_raw();
+            assert_eq!(header, Header::from_raw(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_raw();
+            assert_eq!(block, Block::from_raw(&data));
+        }
+
+        #[test]
+        fn trailing_bytes() {
+            let mut data = Header::random().to_raw();
+            data.push(0);
+            assert_eq!(
+                bench_raw::from_bytes::<Header>(&data).err(),
+                Some(Error::TrailingBytes)
+            );
+        }
+
+        #[test]
+        fn forged_length() {
+            // A block claiming u32::MAX transactions would reserve hundreds of
+            // gigabytes if the count was trusted, instead it runs out of input.
+            let mut data = Block::random(0, 3).to_raw();
+            let len = data.len();
+            data[len - 4..].copy_from_slice(&u32::MAX.to_le_bytes());
+            assert_eq!(
+                bench_raw::from_bytes::<Block>(&data).err(),
+                Some(Error::UnexpectedEof)
+            );
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100).map(|_| Header::random().to_raw().len()).sum();
+            println!("raw header size: {}", size);
+
+            let size: usize = (0..100).map(|_| Block::random(100, 3).to_raw().len()).sum();
+            println!("raw block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "flatbuffers")]
+    mod flatbuffers_struct {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_flatbuffers_struct();
+            assert_eq!(header, Header::from_flatbuffers_struct(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_flatbuffers_struct();
+            assert_eq!(block, Block::from_flatbuffers_struct(&data));
+        }
+
+        #[test]
+        fn smaller_than_flatbuffers() {
+            let block = Block::random(100, 3);
+            assert!(block.to_flatbuffers_struct().len() < block.to_flatbuffers().len());
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100)
+                .map(|_| Header::random().to_flatbuffers_struct().len())
+                .sum();
+            println!("flatbuffers_struct header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_flatbuffers_struct().len())
+                .sum();
+            println!("flatbuffers_struct block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "molecule")]
+    mod molecule_struct {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_molecule_struct();
+            assert_eq!(header, Header::from_molecule_struct(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_molecule_struct();
+            assert_eq!(block, Block::from_molecule_struct(&data));
+        }
+
+        #[test]
+        fn fixed_out_point() {
+            let out_point = OutPoint::random();
+            let data = MolStructOutPoint::new_builder()
+                .hash(MolStructByte32::from_slice(&out_point.hash).unwrap())
+                .index(MolStructUint32::from_slice(&out_point.index.to_le_bytes()).unwrap())
+                .build();
+            assert_eq!(data.as_slice().len(), MolStructOutPoint::TOTAL_SIZE);
+            assert_eq!(&data.as_slice()[..32], &out_point.hash[..]);
+            assert!(MolStructOutPointReader::from_slice(&data.as_slice()[..35]).is_err());
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100)
+                .map(|_| Header::random().to_molecule_struct().len())
+                .sum();
+            println!("molecule_struct header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_molecule_struct().len())
+                .sum();
+            println!("molecule_struct block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "prost")]
+    mod prost_fixed {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_prost_fixed();
+            assert_eq!(header, Header::from_prost_fixed(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_prost_fixed();
+            assert_eq!(block, Block::from_prost_fixed(&data));
+        }
+
+        #[test]
+        fn fixed_width() {
+            let mut header = Header::random();
+            header.seal.nonce = 1;
+            let len = header.to_prost_fixed().len();
+            header.seal.nonce = u64::MAX;
+            assert_eq!(len, header.to_prost_fixed().len());
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100)
+                .map(|_| Header::random().to_prost_fixed().len())
+                .sum();
+            println!("prost_fixed header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_prost_fixed().len())
+                .sum();
+            println!("prost_fixed block size: {}", size);
+        }
+    }
+
+    #[cfg(feature = "flexbuffers")]
+    mod flexbuffers {
+        use super::*;
+
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_flexbuffers();
+            assert_eq!(header, Header::from_flexbuffers(&data));
+        }
+
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_flexbuffers();
+            assert_eq!(block, Block::from_flexbuffers(&data));
+        }
+
+        #[test]
+        fn random_access() {
+            let block = Block::random(100, 3);
+            let data = block.to_flexbuffers();
+            let root = ::flexbuffers::Reader::get_root(&data[..]).unwrap();
+            let transactions = root.as_map().idx("transactions").as_vector();
+            let outputs = transactions
+                .idx(transactions.len() - 1)
+                .as_map()
+                .idx("outputs")
+                .as_vector();
+            let output = outputs.idx(outputs.len() - 1).as_map();
+            let expected = block.transactions.last().unwrap().outputs.last().unwrap();
+            assert_eq!(expected.capacity, output.idx("capacity").as_u64());
+            assert_eq!(&expected.lock[..], output.idx("lock").as_blob().0);
+        }
+
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100)
+                .map(|_| Header::random().to_flexbuffers().len())
+                .sum();
+            println!("flexbuffers header size: {}", size);
+
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_flexbuffers().len())
+                .sum();
+            println!("flexbuffers block size: {}", size);
         }
     }
-}
 
-impl<'a> From<&'a CellInput> for ProtobufCellInput {
-    fn from(cell_input: &CellInput) -> Self {
-        let mut result = ProtobufCellInput::new();
-        result.set_hash(cell_input.previous_output.hash.to_vec());
-        result.set_index(cell_input.previous_output.index);
-        result.set_unlock(cell_input.unlock.to_vec());
-        result
-    }
-}
+    #[cfg(feature = "bitcoin")]
+    mod bitcoin {
+        use super::*;
+        use crate::bench_bitcoin::{decode_compact_size, encode_compact_size, Error, MAX_SIZE};
 
-impl From<MolCellInputReader<'_>> for CellInput {
-    fn from(cell_input: MolCellInputReader) -> Self {
-        CellInput {
-            previous_output: OutPoint {
-                hash: H256::from_slice(cell_input.hash().as_slice()),
-                index: u32::from_le_bytes(cell_input.index().as_slice().try_into().unwrap()),
-            },
-            unlock: cell_input.unlock().raw_data().as_ref().into(),
+        #[test]
+        fn ser_de_header() {
+            let header = Header::random();
+            let data = header.to_bitcoin();
+            assert_eq!(header, Header::from_bitcoin(&data));
         }
-    }
-}
 
-impl CellOutput {
-    pub fn random() -> Self {
-        CellOutput {
-            capacity: thread_rng().gen_range(600, 1000),
-            data: thread_rng().sample_iter(&Standard).take(600).collect(),
-            lock: H256::random(),
+        #[test]
+        fn ser_de_block() {
+            let block = Block::random(100, 3);
+            let data = block.to_bitcoin();
+            assert_eq!(block, Block::from_bitcoin(&data));
         }
-    }
-}
 
-impl<'a> From<FbsCellOutput<'a>> for CellOutput {
-    fn from(cell_output: FbsCellOutput<'a>) -> Self {
-        CellOutput {
-            capacity: cell_output.capacity(),
-            data: cell_output.data().unwrap().to_vec(),
-            lock: H256::from_slice(cell_output.lock().unwrap()),
+        #[test]
+        fn compact_size() {
+            let cases: &[(u64, &[u8])] = &[
+                (0, &[0x00]),
+                (0xfc, &[0xfc]),
+                (0xfd, &[0xfd, 0xfd, 0x00]),
+                (0xffff, &[0xfd, 0xff, 0xff]),
+                (0x1_0000, &[0xfe, 0x00, 0x00, 0x01, 0x00]),
+                (MAX_SIZE, &[0xfe, 0x00, 0x00, 0x00, 0x02]),
+            ];
+            for (size, expected) in cases {
+                let mut data = Vec::new();
+                encode_compact_size(*size, &mut data);
+                assert_eq!(&data[..], *expected);
+                assert_eq!(decode_compact_size(&mut &data[..]), Ok(*size));
+            }
+
+            let mut data = Vec::new();
+            encode_compact_size(0x1_0000_0000, &mut data);
+            assert_eq!(data, [0xff, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00]);
         }
-    }
-}
 
-impl<'a> From<&'a ProtobufCellOutput> for CellOutput {
-    fn from(cell_output: &ProtobufCellOutput) -> Self {
-        CellOutput {
-            capacity: cell_output.get_capacity(),
-            data: cell_output.get_data().to_vec(),
-            lock: H256::from_slice(cell_output.get_lock()),
+        #[test]
+        fn reject_non_canonical() {
+            let non_minimal: &[&[u8]] = &[
+                &[0xfd, 0xfc, 0x00],
+                &[0xfe, 0xff, 0xff, 0x00, 0x00],
+                &[0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00],
+            ];
+            for data in non_minimal {
+                assert_eq!(
+                    decode_compact_size(&mut &data[..]),
+                    Err(Error::NonMinimalCompactSize)
+                );
+            }
+            assert_eq!(
+                decode_compact_size(&mut &[0xfe, 0x01, 0x00, 0x00, 0x02][..]),
+                Err(Error::SizeTooLarge)
+            );
+
+            // The proof length is the only CompactSize in a header, right
+            // after the fixed 156 bytes in front of it.
+            let header = Header::random();
+            let data = header.to_bitcoin();
+            assert_eq!(data[156], 32);
+            let mut non_minimal = data[..156].to_vec();
+            non_minimal.extend_from_slice(&[0xfd, 32, 0]);
+            non_minimal.extend_from_slice(&data[157..]);
+            assert_eq!(
+                bench_bitcoin::from_bytes::<BtcHeader>(&non_minimal).err(),
+                Some(Error::NonMinimalCompactSize)
+            );
+
+            let mut trailing = data.clone();
+            trailing.push(0);
+            assert_eq!(
+                bench_bitcoin::from_bytes::<BtcHeader>(&trailing).err(),
+                Some(Error::TrailingBytes)
+            );
+
+            assert_eq!(
+                bench_bitcoin::from_bytes::<BtcHeader>(&data[..data.len() - 1]).err(),
+                Some(Error::UnexpectedEof)
+            );
         }
-    }
-}
 
-impl<'a> From<&'a CellOutput> for ProtobufCellOutput {
-    fn from(cell_output: &CellOutput) -> Self {
-        let mut result = ProtobufCellOutput::new();
-        result.set_capacity(cell_output.capacity);
-        result.set_data(cell_output.data.to_vec());
-        result.set_lock(cell_output.lock.to_vec());
-        result
-    }
-}
+        #[test]
+        fn data_size() {
+            let size: usize = (0..100).map(|_| Header::random().to_bitcoin().len()).sum();
+            println!("bitcoin header size: {}", size);
 
-impl From<MolCellOutputReader<'_>> for CellOutput {
-    fn from(cell_output: MolCellOutputReader) -> Self {
-        CellOutput {
-            capacity: u64::from_le_bytes(cell_output.capacity().as_slice().try_into().unwrap()),
-            data: cell_output.data().raw_data().as_ref().into(),
-            lock: H256::from_slice(cell_output.lock().as_slice()),
+            let size: usize = (0..100)
+                .map(|_| Block::random(100, 3).to_bitcoin().len())
+                .sum();
+            println!("bitcoin block size: {}", size);
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    mod flatbuffers {
+    #[cfg(feature = "bitcode")]
+    mod bitcode {
         use super::*;
 
         #[test]
         fn ser_de_header() {
             let header = Header::random();
-            let data = header.to_flatbuffers();
-            assert_eq!(header, Header::from_flatbuffers(&data));
+            let data = header.to_bitcode();
+            assert_eq!(header, Header::from_bitcode(&data));
         }
 
         #[test]
         fn ser_de_block() {
             let block = Block::random(100, 3);
-            let data = block.to_flatbuffers();
-            assert_eq!(block, Block::from_flatbuffers(&data));
+            let data = block.to_bitcode();
+            assert_eq!(block, Block::from_bitcode(&data));
         }
 
         #[test]
         fn data_size() {
-            let size: usize = (0..100)
-                .map(|_| Header::random().to_flatbuffers().len())
-                .sum();
-            println!("flatbuffers header size: {}", size);
+            let size: usize = (0..100).map(|_| Header::random().to_bitcode().len()).sum();
+            println!("bitcode header size: {}", size);
 
             let size: usize = (0..100)
-                .map(|_| Block::random(100, 3).to_flatbuffers().len())
+                .map(|_| Block::random(100, 3).to_bitcode().len())
                 .sum();
-            println!("flatbuffers block size: {}", size);
+            println!("bitcode block size: {}", size);
         }
     }
 
-    mod protobuf {
+    #[cfg(feature = "bson")]
+    mod bson {
         use super::*;
+        use ::bson::spec::BinarySubtype;
+        use ::bson::{Bson, Document};
 
         #[test]
         fn ser_de_header() {
             let header = Header::random();
-            let data = header.to_protobuf();
-            assert_eq!(header, Header::from_protobuf(&data));
+            let data = header.to_bson();
+            assert_eq!(header, Header::from_bson(&data));
         }
 
         #[test]
         fn ser_de_block() {
             let block = Block::random(100, 3);
-            let data = block.to_protobuf();
-            assert_eq!(block, Block::from_protobuf(&data));
+            let data = block.to_bson();
+            assert_eq!(block, Block::from_bson(&data));
+        }
+
+        #[test]
+        fn field_types() {
+            let header = Header::random();
+            let document = Document::from_reader(&header.to_bson()[..]).unwrap();
+            match document.get("parent_hash") {
+                Some(Bson::Binary(binary)) => {
+                    assert_eq!(binary.subtype, BinarySubtype::Generic);
+                    assert_eq!(&binary.bytes[..], &header.parent_hash[..]);
+                }
+                other => panic!("unexpected parent_hash {:?}", other),
+            }
+            assert_eq!(
+                document.get("timestamp"),
+                Some(&Bson::Int64(header.timestamp as i64))
+            );
+        }
+
+        #[test]
+        fn int64_range() {
+            let mut header = Header::random();
+            header.timestamp = i64::MAX as u64;
+            assert_eq!(header, Header::from_bson(&header.to_bson()));
+
+            header.timestamp = i64::MAX as u64 + 1;
+            assert!(::bson::to_vec(&BsonHeader::from(&header)).is_err());
+
+            let mut document = Document::from_reader(&Header::random().to_bson()[..]).unwrap();
+            document.insert("number", -1i64);
+            let mut data = Vec::new();
+            document.to_writer(&mut data).unwrap();
+            assert!(::bson::from_slice::<BsonHeader>(&data).is_err());
         }
 
         #[test]
         fn data_size() {
-            let size: usize = (0..100).map(|_| Header::random().to_protobuf().len()).sum();
-            println!("protobuf header size: {}", size);
+            let size: usize = (0..100).map(|_| Header::random().to_bson().len()).sum();
+            println!("bson header size: {}", size);
 
             let size: usize = (0..100)
-                .map(|_| Block::random(100, 3).to_protobuf().len())
+                .map(|_| Block::random(100, 3).to_bson().len())
                 .sum();
-            println!("protobuf block size: {}", size);
+            println!("bson block size: {}", size);
         }
     }
 
-    mod molecule {
+    #[cfg(feature = "sbe")]
+    mod sbe {
         use super::*;
+        use crate::bench_sbe::{
+            Error, MAX_VAR_DATA_LENGTH, MESSAGE_HEADER_LENGTH, ROOT_BLOCK_LENGTH,
+        };
+        use std::convert::TryInto;
 
         #[test]
         fn ser_de_header() {
             let header = Header::random();
-            let data = header.to_molecule();
-            assert_eq!(header, Header::from_molecule(&data));
+            let data = header.to_sbe();
+            assert_eq!(header, Header::from_sbe(&data));
         }
 
         #[test]
         fn ser_de_block() {
             let block = Block::random(100, 3);
-            let data = block.to_molecule();
-            assert_eq!(block, Block::from_molecule(&data));
+            let data = block.to_sbe();
+            assert_eq!(block, Block::from_sbe(&data));
+        }
+
+        #[test]
+        fn fixed_offsets() {
+            // `number` is at offset 44 of the root block in both messages, so
+            // it can be read without decoding anything else.
+            let block = Block::random(10, 3);
+            for data in &[block.header.to_sbe(), block.to_sbe()] {
+                let offset = MESSAGE_HEADER_LENGTH + 44;
+                let number = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+                assert_eq!(block.header.number, number);
+            }
+        }
+
+        #[test]
+        fn schema_extension() {
+            // A newer schema version may append fields to the root block, an
+            // older decoder skips them using the block length.
+            let header = Header::random();
+            let data = header.to_sbe();
+            let root_end = MESSAGE_HEADER_LENGTH + ROOT_BLOCK_LENGTH as usize;
+            let mut extended = data[..root_end].to_vec();
+            extended[..2].copy_from_slice(&(ROOT_BLOCK_LENGTH + 8).to_le_bytes());
+            extended.extend_from_slice(&[0xff; 8]);
+            extended.extend_from_slice(&data[root_end..]);
+            assert_eq!(header, Header::from_sbe(&extended));
+
+            let mut truncated = data.clone();
+            truncated[..2].copy_from_slice(&(ROOT_BLOCK_LENGTH - 1).to_le_bytes());
+            assert_eq!(
+                bench_sbe::from_bytes::<SbeHeader>(&truncated).err(),
+                Some(Error::BlockLengthTooShort)
+            );
+        }
+
+        #[test]
+        fn reject_invalid() {
+            let data = Header::random().to_sbe();
+            assert_eq!(
+                bench_sbe::from_bytes::<SbeBlock>(&data).err(),
+                Some(Error::UnknownTemplate(1))
+            );
+
+            let mut trailing = data.clone();
+            trailing.push(0);
+            assert_eq!(
+                bench_sbe::from_bytes::<SbeHeader>(&trailing).err(),
+                Some(Error::TrailingBytes)
+            );
+
+            assert_eq!(
+                bench_sbe::from_bytes::<SbeHeader>(&data[..data.len() - 1]).err(),
+                Some(Error::UnexpectedEof)
+            );
+        }
+
+        #[test]
+        fn limits() {
+            let mut block = SbeBlock::from(&Block::random(1, 3));
+            block.transactions[0].deps = (0..=u16::MAX as u32)
+                .map(|index| SbeOutPoint {
+                    hash: [0; 32],
+                    index,
+                })
+                .collect();
+            assert_eq!(
+                bench_sbe::to_bytes(&block).err(),
+                Some(Error::GroupTooLong(u16::MAX as usize + 1))
+            );
+
+            // `proof` is the last field of a header, its length sits right
+            // after the root block.
+            let mut data = Header::random().to_sbe();
+            let offset = MESSAGE_HEADER_LENGTH + ROOT_BLOCK_LENGTH as usize;
+            let len = MAX_VAR_DATA_LENGTH as u32 + 1;
+            data[offset..offset + 4].copy_from_slice(&len.to_le_bytes());
+            assert_eq!(
+                bench_sbe::from_bytes::<SbeHeader>(&data).err(),
+                Some(Error::VarDataTooLong(len as usize))
+            );
         }
 
         #[test]
         fn data_size() {
-            let size: usize = (0..100).map(|_| Header::random().to_molecule().len()).sum();
-            println!("molecule header size: {}", size);
+            let size: usize = (0..100).map(|_| Header::random().to_sbe().len()).sum();
+            println!("sbe header size: {}", size);
 
-            let size: usize = (0..100)
-                .map(|_| Block::random(100, 3).to_molecule().len())
-                .sum();
-            println!("molecule block size: {}", size);
+            let size: usize = (0..100).map(|_| Block::random(100, 3).to_sbe().len()).sum();
+            println!("sbe block size: {}", size);
+        }
+    }
+
+    // With no format enabled there are no tests to use the helpers.
+    #[allow(dead_code)]
+    mod allocations {
+        use super::*;
+        use crate::alloc_counter::measure;
+
+        // Bounds are the allocations measured for each call with a few percent of
+        // headroom for the count and about 10% for the bytes, which vary a little
+        // with the random values. Run `cargo bench --bench allocations` for the
+        // current numbers when a change is expected to move them.
+        //
+        // Only raw, bitcoin, xdr, sbe and ssz are held to them. Every other format
+        // allocates however the release of its crate that Cargo resolves does, and
+        // Cargo.lock is not committed, so those are checked against twice their
+        // bounds to catch our own conversions regressing, not upstream changes.
+
+        fn block() -> Block {
+            Block::random(100, 3)
+        }
+
+        /// Encodes `value` with `to` and decodes the result with `from`, asserting
+        /// that each call stays within its `(count, bytes)` allocation bounds.
+        fn assert_round_trip<T, O: AsRef<[u8]>, R>(
+            value: &T,
+            to: fn(&T) -> O,
+            from: fn(&[u8]) -> R,
+            max_to: (u64, u64),
+            max_from: (u64, u64),
+        ) {
+            let (data, allocations) = measure(|| to(value));
+            assert!(
+                allocations.count <= max_to.0 && allocations.bytes <= max_to.1,
+                "encoding made {:?}, expected at most {:?}",
+                allocations,
+                max_to
+            );
+            let (_, allocations) = measure(|| from(data.as_ref()));
+            assert!(
+                allocations.count <= max_from.0 && allocations.bytes <= max_from.1,
+                "decoding made {:?}, expected at most {:?}",
+                allocations,
+                max_from
+            );
+        }
+
+        /// `assert_round_trip` with twice the bounds, for formats backed by a
+        /// third-party crate.
+        fn assert_round_trip_loosely<T, O: AsRef<[u8]>, R>(
+            value: &T,
+            to: fn(&T) -> O,
+            from: fn(&[u8]) -> R,
+            max_to: (u64, u64),
+            max_from: (u64, u64),
+        ) {
+            let twice = |(count, bytes): (u64, u64)| (count * 2, bytes * 2);
+            assert_round_trip(value, to, from, twice(max_to), twice(max_from));
+        }
+
+        #[test]
+        #[cfg(feature = "flatbuffers")]
+        fn flatbuffers() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_flatbuffers,
+                Header::from_flatbuffers,
+                (13, 1800),
+                (2, 36),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_flatbuffers,
+                Block::from_flatbuffers,
+                (341, 1_500_000),
+                (953, 330_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "protobuf")]
+        fn protobuf() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_protobuf,
+                Header::from_protobuf,
+                (9, 530),
+                (9, 290),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_protobuf,
+                Block::from_protobuf,
+                (1910, 620_000),
+                (2860, 680_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "molecule")]
+        fn molecule() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_molecule,
+                Header::from_molecule,
+                (31, 1400),
+                (4, 330),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_molecule,
+                Block::from_molecule,
+                (13_700, 3_200_000),
+                (3690, 430_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "scale")]
+        fn scale() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_scale,
+                Header::from_scale,
+                (3, 310),
+                (2, 36),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_scale,
+                Block::from_scale,
+                (954, 870_000),
+                (948, 300_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "borsh")]
+        fn borsh() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_borsh,
+                Header::from_borsh,
+                (3, 1200),
+                (2, 36),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_borsh,
+                Block::from_borsh,
+                (957, 870_000),
+                (949, 300_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "ssz")]
+        fn ssz() {
+            assert_round_trip(
+                &Header::random(),
+                Header::to_ssz,
+                Header::from_ssz,
+                (5, 810),
+                (5, 350),
+            );
+            assert_round_trip(
+                &block(),
+                Block::to_ssz,
+                Block::from_ssz,
+                (2960, 2_900_000),
+                (4420, 830_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "rkyv")]
+        fn rkyv() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_rkyv,
+                Header::from_rkyv,
+                (9, 920),
+                (2, 36),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_rkyv,
+                Block::from_rkyv,
+                (20, 590_000),
+                (948, 300_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "rkyv")]
+        fn rkyv_unchecked() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_rkyv,
+                |data| unsafe { Header::from_rkyv_unchecked(data) },
+                (9, 920),
+                (2, 36),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_rkyv,
+                |data| unsafe { Block::from_rkyv_unchecked(data) },
+                (20, 590_000),
+                (948, 300_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "prost")]
+        fn prost() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_prost,
+                Header::from_prost,
+                (9, 530),
+                (15, 500),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_prost,
+                Block::from_prost,
+                (1900, 600_000),
+                (3810, 670_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "quick-protobuf")]
+        fn quick_protobuf() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_quick_protobuf,
+                Header::from_quick_protobuf,
+                (3, 320),
+                (2, 36),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_quick_protobuf,
+                Block::from_quick_protobuf,
+                (319, 330_000),
+                (1270, 380_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "quick-protobuf")]
+        fn quick_protobuf_borrowed() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_quick_protobuf,
+                |data| {
+                    QuickHeader::from_reader(&mut BytesReader::from_bytes(data), data).unwrap();
+                },
+                (3, 320),
+                (0, 0),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_quick_protobuf,
+                |data| {
+                    QuickBlock::from_reader(&mut BytesReader::from_bytes(data), data).unwrap();
+                },
+                (319, 330_000),
+                (322, 86_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "avro")]
+        fn avro() {
+            // The schemas are parsed on first use, keep that out of the counts.
+            avro_schemas();
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_avro,
+                Header::from_avro,
+                (90, 3500),
+                (51, 1900),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_avro,
+                Block::from_avro,
+                (10_200, 1_400_000),
+                (8290, 690_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "avro")]
+        fn avro_resolved() {
+            avro_schemas();
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_avro,
+                Block::from_avro_resolved,
+                (10_200, 1_400_000),
+                (14_400, 1_500_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "avro")]
+        fn avro_container() {
+            avro_schemas();
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_avro_container,
+                Block::from_avro_container,
+                (10_200, 2_100_000),
+                (8870, 1_100_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "thrift")]
+        fn thrift_binary() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_thrift_binary,
+                Header::from_thrift_binary,
+                (27, 1200),
+                (8, 250),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_thrift_binary,
+                Block::from_thrift_binary,
+                (5930, 930_000),
+                (2220, 380_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "thrift")]
+        fn thrift_compact() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_thrift_compact,
+                Header::from_thrift_compact,
+                (27, 980),
+                (9, 260),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_thrift_compact,
+                Block::from_thrift_compact,
+                (5930, 1_100_000),
+                (2220, 380_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "json")]
+        fn json() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_json,
+                Header::from_json,
+                (13, 2700),
+                (2, 36),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_json,
+                Block::from_json,
+                (2550, 2_000_000),
+                (953, 330_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "json")]
+        fn json_simd() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_json,
+                Header::from_json_simd,
+                (13, 2700),
+                (11, 5800),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_json,
+                Block::from_json_simd,
+                (2550, 2_000_000),
+                (956, 3_100_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "bcs")]
+        fn bcs() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_bcs,
+                Header::from_bcs,
+                (8, 590),
+                (2, 36),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_bcs,
+                Block::from_bcs,
+                (964, 880_000),
+                (948, 300_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "postcard")]
+        fn postcard() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_postcard,
+                Header::from_postcard,
+                (8, 590),
+                (2, 36),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_postcard,
+                Block::from_postcard,
+                (964, 880_000),
+                (948, 300_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "postcard")]
+        fn postcard_heapless() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_postcard,
+                Header::from_postcard_heapless,
+                (8, 590),
+                (0, 0),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "der")]
+        fn der() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_der,
+                Header::from_der,
+                (3, 330),
+                (2, 36),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_der,
+                Block::from_der,
+                (949, 570_000),
+                (953, 330_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "xdr")]
+        fn xdr() {
+            assert_round_trip(
+                &Header::random(),
+                Header::to_xdr,
+                Header::from_xdr,
+                (7, 640),
+                (2, 36),
+            );
+            assert_round_trip(
+                &block(),
+                Block::to_xdr,
+                Block::from_xdr,
+                (963, 950_000),
+                (948, 300_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "raw")]
+        fn raw() {
+            assert_round_trip(
+                &Header::random(),
+                Header::to_raw,
+                Header::from_raw,
+                (6, 610),
+                (2, 36),
+            );
+            assert_round_trip(
+                &block(),
+                Block::to_raw,
+                Block::from_raw,
+                (16, 650_000),
+                (948, 300_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "flatbuffers")]
+        fn flatbuffers_struct() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_flatbuffers_struct,
+                Header::from_flatbuffers_struct,
+                (13, 1800),
+                (2, 36),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_flatbuffers_struct,
+                Block::from_flatbuffers_struct,
+                (340, 880_000),
+                (953, 330_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "molecule")]
+        fn molecule_struct() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_molecule_struct,
+                Header::from_molecule_struct,
+                (33, 1800),
+                (4, 120),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_molecule_struct,
+                Block::from_molecule_struct,
+                (14_000, 3_200_000),
+                (2850, 400_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "prost")]
+        fn prost_fixed() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_prost_fixed,
+                Header::from_prost_fixed,
+                (9, 550),
+                (15, 500),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_prost_fixed,
+                Block::from_prost_fixed,
+                (1900, 600_000),
+                (3810, 670_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "flexbuffers")]
+        fn flexbuffers() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_flexbuffers,
+                Header::from_flexbuffers,
+                (17, 2800),
+                (2, 36),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_flexbuffers,
+                Block::from_flexbuffers,
+                (978, 1_500_000),
+                (948, 300_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "bitcoin")]
+        fn bitcoin() {
+            assert_round_trip(
+                &Header::random(),
+                Header::to_bitcoin,
+                Header::from_bitcoin,
+                (7, 640),
+                (2, 36),
+            );
+            assert_round_trip(
+                &block(),
+                Block::to_bitcoin,
+                Block::from_bitcoin,
+                (963, 950_000),
+                (948, 300_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "bitcode")]
+        fn bitcode() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_bitcode,
+                Header::from_bitcode,
+                (20, 1100),
+                (6, 160),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_bitcode,
+                Block::from_bitcode,
+                (1090, 2_200_000),
+                (962, 310_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "bson")]
+        fn bson() {
+            assert_round_trip_loosely(
+                &Header::random(),
+                Header::to_bson,
+                Header::from_bson,
+                (9, 1200),
+                (2, 36),
+            );
+            assert_round_trip_loosely(
+                &block(),
+                Block::to_bson,
+                Block::from_bson,
+                (965, 1_500_000),
+                (953, 330_000),
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "sbe")]
+        fn sbe() {
+            assert_round_trip(
+                &Header::random(),
+                Header::to_sbe,
+                Header::from_sbe,
+                (8, 790),
+                (2, 36),
+            );
+            assert_round_trip(
+                &block(),
+                Block::to_sbe,
+                Block::from_sbe,
+                (964, 1_100_000),
+                (948, 300_000),
+            );
         }
     }
 }
@@ -0,0 +1,40 @@
+//! Slice reads shared by the hand-written decoders: `bench_bitcoin`,
+//! `bench_raw`, `bench_sbe` and `bench_xdr`. Each converts `UnexpectedEof`
+//! into its own `Error`.
+
+use std::convert::TryInto;
+
+pub struct UnexpectedEof;
+
+/// Splits the first `len` bytes off `input`.
+pub fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], UnexpectedEof> {
+    if input.len() < len {
+        return Err(UnexpectedEof);
+    }
+    let (head, tail) = input.split_at(len);
+    *input = tail;
+    Ok(head)
+}
+
+/// Splits the first `N` bytes off `input`, for integers and hashes.
+pub fn array<const N: usize>(input: &mut &[u8]) -> Result<[u8; N], UnexpectedEof> {
+    Ok(take(input, N)?.try_into().unwrap())
+}
+
+/// Decodes `count` items that each take at least `min_size` bytes.
+///
+/// The count comes from the input, so the reservation is capped at what the
+/// remaining input could hold: a forged count then runs out of input instead
+/// of reserving gigabytes up front.
+pub fn vec<T, E>(
+    input: &mut &[u8],
+    count: usize,
+    min_size: usize,
+    mut item: impl FnMut(&mut &[u8]) -> Result<T, E>,
+) -> Result<Vec<T>, E> {
+    let mut items = Vec::with_capacity(count.min(input.len() / min_size.max(1)));
+    for _ in 0..count {
+        items.push(item(input)?);
+    }
+    Ok(items)
+}